@@ -0,0 +1,185 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use aoc_grid::ParseGridError;
+use nom::{
+    error::{ErrorKind, VerboseError, VerboseErrorKind},
+    Offset,
+};
+
+/// Characters of context kept either side of the error column in long lines.
+const SNIPPET_RADIUS: usize = 40;
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    snippet: String,
+    snippet_column: usize,
+    expected: String,
+}
+
+impl ParseError {
+    /// Reports an error at a byte offset into `input`.
+    pub fn at(input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        let line = input[..line_start].matches('\n').count() + 1;
+        let text = input[line_start..line_end].trim_end_matches('\r');
+        let column = input[line_start..offset].chars().count() + 1;
+
+        let skip = (column - 1).saturating_sub(SNIPPET_RADIUS);
+        let snippet = text.chars().skip(skip).take(SNIPPET_RADIUS * 2).collect();
+
+        ParseError {
+            line,
+            column,
+            snippet,
+            snippet_column: column - skip,
+            expected: expected.into(),
+        }
+    }
+
+    /// Reports an error at a 1-based line and column of `input`.
+    pub fn at_line(input: &str, line: usize, column: usize, expected: impl Into<String>) -> Self {
+        let line_start = input
+            .split_inclusive('\n')
+            .take(line - 1)
+            .map(str::len)
+            .sum::<usize>();
+        let offset = input[line_start..]
+            .char_indices()
+            .nth(column - 1)
+            .map_or(input.len(), |(i, _)| line_start + i);
+
+        ParseError::at(input, offset, expected)
+    }
+
+    /// Reports a grid line of the wrong length, at the column where it stops matching the others.
+    pub fn from_grid(input: &str, e: ParseGridError) -> Self {
+        ParseError::at_line(
+            input,
+            e.line,
+            e.column(),
+            format!("{} cells in line", e.expected),
+        )
+    }
+
+    pub fn from_nom(input: &str, e: nom::Err<VerboseError<&str>>) -> Self {
+        let errors = match e {
+            nom::Err::Error(e) | nom::Err::Failure(e) => e.errors,
+            nom::Err::Incomplete(_) => return ParseError::at(input, input.len(), "more input"),
+        };
+
+        let Some((remaining, kind)) = errors.first() else {
+            return ParseError::at(input, 0, "valid input");
+        };
+
+        let mut expected = describe(kind);
+
+        for (_, kind) in errors.iter().skip(1) {
+            if let VerboseErrorKind::Context(context) = kind {
+                expected = format!("{} in {}", expected, context);
+                break;
+            }
+        }
+
+        ParseError::at(input, input.offset(remaining), expected)
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = self.line.to_string();
+
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{} | {}", gutter, self.snippet)?;
+        write!(
+            f,
+            "{} | {}^",
+            " ".repeat(gutter.len()),
+            " ".repeat(self.snippet_column - 1)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+fn describe(kind: &VerboseErrorKind) -> String {
+    match kind {
+        VerboseErrorKind::Context(context) => context.to_string(),
+        VerboseErrorKind::Char('\n') => "newline".to_owned(),
+        VerboseErrorKind::Char(c) => format!("{:?}", c),
+        VerboseErrorKind::Nom(ErrorKind::Digit) => "digit".to_owned(),
+        VerboseErrorKind::Nom(ErrorKind::Space | ErrorKind::MultiSpace) => "whitespace".to_owned(),
+        VerboseErrorKind::Nom(ErrorKind::CrLf) => "newline".to_owned(),
+        VerboseErrorKind::Nom(ErrorKind::Eof) => "end of input".to_owned(),
+        VerboseErrorKind::Nom(ErrorKind::Tag) => "literal text".to_owned(),
+        VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+    }
+}
+
+pub type Result<T, E = ParseError> = std::result::Result<T, E>;
+
+pub type IResult<'a, T> = nom::IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// Parses each line of `input` in full, reporting errors relative to the whole input.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> Result<Vec<T>> {
+    input
+        .lines()
+        .map(|line| match parser(line) {
+            Ok(("", value)) => Ok(value),
+            Ok((remaining, _)) => Err(ParseError::at(
+                input,
+                input.offset(remaining),
+                "end of line",
+            )),
+            Err(e) => Err(ParseError::from_nom(input, e)),
+        })
+        .collect()
+}
+
+/// Parses the whole of `input`, which must be consumed in full.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> Result<T> {
+    match parser(input) {
+        Ok((remaining, value)) if remaining.trim().is_empty() => Ok(value),
+        Ok((remaining, _)) => Err(ParseError::at(
+            input,
+            input.offset(remaining),
+            "end of input",
+        )),
+        Err(e) => Err(ParseError::from_nom(input, e)),
+    }
+}
@@ -0,0 +1,82 @@
+use std::{fmt::Write, fs, path::Path};
+
+use crate::{solve, Part, Solution, Solved, Source};
+
+/// Name of the manifest in a day's `examples` directory. Each non-blank, non-comment line names
+/// an input file (relative to the directory) followed by the expected answers for part 1 and
+/// part 2, with `-` for answers that should not be checked.
+pub const MANIFEST: &str = "answers.txt";
+
+/// Runs a day against every input listed in `<crate_dir>/examples/answers.txt`, panicking with a
+/// report of every answer that differs from the manifest.
+pub fn check_examples<S: Solution>(crate_dir: &str) {
+    let dir = Path::new(crate_dir).join("examples");
+    let manifest = fs::read_to_string(dir.join(MANIFEST))
+        .unwrap_or_else(|e| panic!("could not read {}: {}", dir.join(MANIFEST).display(), e));
+
+    let mut report = String::new();
+    let mut checked = 0;
+
+    for (number, line) in manifest.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [file, expected @ ..] = fields.as_slice() else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            expected.len(),
+            Part::ALL.len(),
+            "{} line {}: expected a file name and {} answers",
+            MANIFEST,
+            number + 1,
+            Part::ALL.len()
+        );
+
+        let input = Source::Path(dir.join(file))
+            .read(S::DAY)
+            .unwrap_or_else(|e| panic!("{}", e));
+
+        let parts: Vec<Part> = Part::ALL
+            .into_iter()
+            .zip(expected.iter())
+            .filter(|&(_, &answer)| answer != "-")
+            .map(|(part, _)| part)
+            .collect();
+
+        let outcome = match solve::<S>(&input, &parts) {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                writeln!(report, "day {} {}: parse failed\n{}\n", S::DAY, file, e).unwrap();
+                continue;
+            }
+        };
+
+        for Solved { part, answer, .. } in outcome.answers {
+            let expected = expected[Part::ALL.iter().position(|&p| p == part).unwrap()];
+            let actual = answer.to_string();
+            checked += 1;
+
+            if actual != expected {
+                writeln!(
+                    report,
+                    "day {} {} part {}:\n  - {}\n  + {}\n",
+                    S::DAY,
+                    file,
+                    part,
+                    expected,
+                    actual
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    assert!(report.is_empty(), "answers changed:\n\n{}", report);
+    assert!(checked > 0, "{} lists no answers to check", MANIFEST);
+}
@@ -0,0 +1,136 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::{self, stdin, BufRead, BufReader, IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+/// Directory, relative to the working directory, holding inputs named `day-<n>.txt`.
+pub const INPUTS_DIR: &str = "inputs";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    Stdin,
+    /// A file, or a directory containing `day-<n>.txt`.
+    Path(PathBuf),
+    /// Stdin, unless it is empty, in which case the file. Stdin is not a terminal in scripts and
+    /// scheduled jobs even when nothing is piped into it.
+    StdinOr(PathBuf),
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: Source,
+    error: io::Error,
+}
+
+impl Source {
+    /// Interprets a command-line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::Path(PathBuf::from(arg))
+        }
+    }
+
+    /// Stdin if something is piped into it, otherwise the conventional input file for the day.
+    pub fn detect(day: u8) -> Self {
+        if stdin().is_terminal() {
+            Source::Path(default_path(day))
+        } else {
+            Source::StdinOr(default_path(day))
+        }
+    }
+
+    /// Resolves the file to read for a day, looking inside directories for `day-<n>.txt`.
+    pub fn resolve(&self, day: u8) -> Source {
+        match self {
+            Source::Path(path) if path.is_dir() => Source::Path(path.join(file_name(day))),
+            source => source.clone(),
+        }
+    }
+
+    /// Reads and normalizes the input for a day.
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        let source = self.resolve(day);
+        let mut buffer = String::new();
+
+        let result = match &source {
+            Source::Stdin => stdin().read_to_string(&mut buffer).map(|_| ()),
+            Source::Path(path) => fs::read_to_string(path).map(|contents| buffer = contents),
+            Source::StdinOr(path) => match stdin().read_to_string(&mut buffer) {
+                Ok(0) => return Source::Path(path.clone()).read(day),
+                result => result.map(|_| ()),
+            },
+        };
+
+        match result {
+            Ok(()) => Ok(normalize(&buffer)),
+            Err(error) => Err(InputError { source, error }),
+        }
+    }
+
+    /// Opens the input for a day to be read incrementally. Unlike [`Source::read`], the text is
+    /// not normalized.
+    pub fn open(&self, day: u8) -> Result<Box<dyn BufRead>, InputError> {
+        let source = self.resolve(day);
+
+        match &source {
+            Source::Stdin => Ok(Box::new(stdin().lock())),
+            Source::Path(path) => match fs::File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(error) => Err(InputError { source, error }),
+            },
+            Source::StdinOr(path) => {
+                let mut stdin = stdin().lock();
+
+                match stdin.fill_buf() {
+                    Ok([]) => Source::Path(path.clone()).open(day),
+                    Ok(_) => Ok(Box::new(stdin)),
+                    Err(error) => Err(InputError {
+                        source: Source::Stdin,
+                        error,
+                    }),
+                }
+            }
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::StdinOr(path) => write!(f, "stdin or {}", path.display()),
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not read {}: {}", self.source, self.error)
+    }
+}
+
+impl Error for InputError {}
+
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(INPUTS_DIR).join(file_name(day))
+}
+
+fn file_name(day: u8) -> String {
+    format!("day-{}.txt", day)
+}
+
+/// Converts CRLF line endings to LF and strips any byte order mark and trailing newlines, so
+/// parsers see the same text however the file was saved.
+pub fn normalize(raw: &str) -> String {
+    raw.strip_prefix('\u{feff}')
+        .unwrap_or(raw)
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_owned()
+}
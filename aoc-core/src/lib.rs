@@ -0,0 +1,101 @@
+use std::{env, process::exit};
+
+mod answer;
+mod error;
+mod examples;
+mod input;
+mod output;
+mod solution;
+
+pub use answer::Answer;
+pub use error::{parse_all, parse_lines, IResult, ParseError, Result};
+pub use examples::check_examples;
+pub use input::{default_path, normalize, InputError, Source, INPUTS_DIR};
+pub use output::{format_answer, format_duration, json_string, Format};
+pub use solution::{solve, Outcome, Part, Solution, Solved};
+
+/// A report a day's binary can print instead of its answers, selected by a flag such as
+/// `--explain`.
+pub type Report<S> = (&'static str, fn(&<S as Solution>::Input) -> String);
+
+/// Entry point for a day's binary: reads the input and prints both answers.
+///
+/// Accepts `--input <path|->` to choose where the input is read from (by default stdin if it is
+/// piped, otherwise `inputs/day-<n>.txt`) and `--format <plain|labelled|json>` to choose how
+/// answers are printed.
+pub fn main<S: Solution>() {
+    main_with::<S>(&[]);
+}
+
+/// Like [`main`], but also accepts the flag of each report, which prints that report for the
+/// parsed input in place of the answers.
+pub fn main_with<S: Solution>(reports: &[Report<S>]) {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let usage = || -> ! {
+        let flags: String = reports
+            .iter()
+            .map(|(flag, _)| format!(" [{}]", flag))
+            .collect();
+
+        eprintln!(
+            "usage: day-{} [--input <path|->] [--format <plain|labelled|json>]{}",
+            S::DAY,
+            flags
+        );
+        exit(2);
+    };
+
+    let mut source = Source::detect(S::DAY);
+    let mut format = Format::default();
+    let mut report = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        if let Some(&(_, r)) = reports.iter().find(|(flag, _)| flag == arg) {
+            report = Some(r);
+            continue;
+        }
+
+        let value = args.next().unwrap_or_else(|| usage());
+
+        match arg.as_str() {
+            "--input" => source = Source::from_arg(value),
+            "--format" => {
+                format = value.parse().unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    usage();
+                })
+            }
+            _ => usage(),
+        }
+    }
+
+    let input = source.read(S::DAY).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        exit(1);
+    });
+
+    if let Some(report) = report {
+        match S::parse(&input) {
+            Ok(input) => print!("{}", report(&input)),
+            Err(e) => {
+                eprintln!("{}", e);
+                exit(1);
+            }
+        }
+
+        return;
+    }
+
+    match solve::<S>(&input, &Part::ALL) {
+        Ok(outcome) => {
+            for solved in outcome.answers {
+                println!("{}", format_answer(S::DAY, &solved, format));
+            }
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(1);
+        }
+    }
+}
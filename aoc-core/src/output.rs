@@ -0,0 +1,91 @@
+use std::{str::FromStr, time::Duration};
+
+use crate::{Answer, Solved};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    /// One bare answer per line, in part order.
+    #[default]
+    Plain,
+    /// One answer per line, labelled with the day, part and time taken.
+    Labelled,
+    /// One JSON record per line.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Format::Plain),
+            "labelled" => Ok(Format::Labelled),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "invalid format {:?}, expected plain, labelled or json",
+                s
+            )),
+        }
+    }
+}
+
+pub fn format_answer(day: u8, solved: &Solved, format: Format) -> String {
+    match format {
+        Format::Plain => solved.answer.to_string(),
+        Format::Labelled => format!(
+            "day {} part {}: {} ({})",
+            day,
+            solved.part,
+            solved.answer,
+            format_duration(solved.elapsed)
+        ),
+        Format::Json => {
+            let answer = match &solved.answer {
+                Answer::Number(n) => n.to_string(),
+                Answer::Text(s) => json_string(s),
+            };
+
+            format!(
+                "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{}}}",
+                day,
+                solved.part,
+                answer,
+                solved.elapsed.as_nanos()
+            )
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
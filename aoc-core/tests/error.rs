@@ -0,0 +1,99 @@
+use aoc_core::{parse_all, parse_lines, IResult, ParseError};
+use aoc_grid::Grid;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{newline, u32},
+    error::context,
+    multi::separated_list1,
+    sequence::preceded,
+};
+
+#[test]
+fn at_finds_line_and_column_of_an_offset() {
+    let input = "first\nsecond line\nthird";
+    let e = ParseError::at(input, input.find("line").unwrap(), "digit");
+
+    assert_eq!((e.line(), e.column()), (2, 8));
+    assert_eq!(e.snippet(), "second line");
+    assert_eq!(e.expected(), "digit");
+    assert_eq!(
+        e.to_string(),
+        "line 2, column 8: expected digit\n2 | second line\n  |        ^"
+    );
+
+    // past the end, and at the very start of a line
+    let e = ParseError::at(input, 1000, "more input");
+    assert_eq!((e.line(), e.column()), (3, 6));
+
+    let e = ParseError::at(input, input.find("third").unwrap(), "x");
+    assert_eq!((e.line(), e.column()), (3, 1));
+}
+
+#[test]
+fn at_counts_columns_in_characters() {
+    let input = "héllo wörld\r\nnext";
+    let e = ParseError::at(input, input.find('w').unwrap(), "x");
+
+    assert_eq!((e.line(), e.column()), (1, 7));
+    assert_eq!(e.snippet(), "héllo wörld");
+
+    let e = ParseError::at_line(input, 1, 9, "x");
+    assert_eq!(e.column(), 9);
+    assert!(e.to_string().ends_with("        ^"));
+}
+
+#[test]
+fn at_line_matches_at() {
+    let input = "a\nbc\ndéf";
+
+    for (line, column, offset) in [(1, 1, 0), (2, 2, 3), (3, 3, 8)] {
+        assert_eq!(
+            ParseError::at_line(input, line, column, "x"),
+            ParseError::at(input, offset, "x")
+        );
+    }
+}
+
+#[test]
+fn long_lines_are_clipped_around_the_column() {
+    let line: String = (0..200)
+        .map(|i| char::from(b'a' + (i % 26) as u8))
+        .collect();
+    let e = ParseError::at_line(&line, 1, 101, "x");
+
+    assert_eq!(e.column(), 101);
+    assert_eq!(e.snippet().chars().count(), 80);
+    assert_eq!(e.snippet(), &line[60..140]);
+
+    // the caret still points at the column within the snippet
+    let caret = e.to_string().lines().last().unwrap().find('^').unwrap();
+    assert_eq!(caret, "1 | ".len() + 40);
+}
+
+fn numbers(i: &str) -> IResult<'_, Vec<u32>> {
+    separated_list1(newline, context("number", preceded(tag("n="), u32)))(i)
+}
+
+#[test]
+fn from_nom_reports_the_innermost_error_with_its_context() {
+    let input = "n=1\nn=2\nn=x";
+    let e = parse_all(input, numbers).unwrap_err();
+
+    assert_eq!((e.line(), e.column()), (2, 4));
+    assert_eq!(e.expected(), "end of input");
+
+    let e = parse_lines(input, |i| context("number", preceded(tag("n="), u32))(i)).unwrap_err();
+
+    assert_eq!((e.line(), e.column()), (3, 3));
+    assert_eq!(e.expected(), "digit in number");
+}
+
+#[test]
+fn from_grid_points_at_the_short_line() {
+    let input = "abc\nab\nabc";
+    let e = Grid::parse(input, |c| c).unwrap_err();
+    let e = ParseError::from_grid(input, e);
+
+    assert_eq!((e.line(), e.column()), (2, 3));
+    assert_eq!(e.expected(), "3 cells in line");
+}
@@ -0,0 +1,41 @@
+use std::{env, fs, path::PathBuf};
+
+use aoc_core::{normalize, Source};
+
+#[test]
+fn normalize_strips_bom_crlf_and_trailing_newlines() {
+    assert_eq!(normalize("\u{feff}1 2\r\n3 4\r\n\r\n"), "1 2\n3 4");
+    assert_eq!(normalize("a\n\nb\n\n\n"), "a\n\nb");
+    assert_eq!(normalize("no newline"), "no newline");
+    assert_eq!(normalize(""), "");
+
+    // a byte order mark is only stripped at the very start
+    assert_eq!(normalize("a\u{feff}"), "a\u{feff}");
+}
+
+#[test]
+fn resolve_looks_inside_directories() {
+    let dir = env::temp_dir().join(format!("aoc-core-input-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("day-3.txt"), "\u{feff}mul(2,4)\r\n").unwrap();
+
+    let source = Source::Path(dir.clone());
+
+    assert_eq!(source.resolve(3), Source::Path(dir.join("day-3.txt")));
+    assert_eq!(source.read(3).unwrap(), "mul(2,4)");
+    assert!(source.read(4).is_err());
+
+    let file = Source::Path(dir.join("day-3.txt"));
+    assert_eq!(file.resolve(4), file);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn from_arg_maps_dash_to_stdin() {
+    assert_eq!(Source::from_arg("-"), Source::Stdin);
+    assert_eq!(
+        Source::from_arg("inputs"),
+        Source::Path(PathBuf::from("inputs"))
+    );
+}
@@ -0,0 +1,399 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point(pub usize, pub usize);
+
+/// Up, right, down, left.
+pub static DIRECTIONS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Clockwise from up-left.
+pub static DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParseGridError {
+    /// 1-based line number of the offending line.
+    pub line: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl ParseGridError {
+    /// 1-based column at which the offending line diverges from the expected length.
+    pub fn column(&self) -> usize {
+        self.expected.min(self.found) + 1
+    }
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "inconsistent line length on line {}: expected {} cells, found {}",
+            self.line, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseGridError {}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "cell count does not match size"
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point(x, y)))
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from lines of text, mapping each character to a cell. Every line must have
+    /// the same number of characters.
+    pub fn from_lines<'a>(
+        lines: impl IntoIterator<Item = &'a str>,
+        mut f: impl FnMut(char) -> T,
+    ) -> Result<Self, ParseGridError> {
+        let mut grid = Grid {
+            width: 0,
+            height: 0,
+            cells: vec![],
+        };
+
+        for line in lines {
+            let before = grid.cells.len();
+            grid.cells.extend(line.chars().map(&mut f));
+            let found = grid.cells.len() - before;
+
+            if grid.height == 0 {
+                grid.width = found;
+            } else if found != grid.width {
+                return Err(ParseGridError {
+                    line: grid.height + 1,
+                    expected: grid.width,
+                    found,
+                });
+            }
+
+            grid.height += 1;
+        }
+
+        Ok(grid)
+    }
+
+    pub fn parse(input: &str, f: impl FnMut(char) -> T) -> Result<Self, ParseGridError> {
+        Self::from_lines(input.lines(), f)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, Point(x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self[point])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self[point])
+        } else {
+            None
+        }
+    }
+
+    /// Offsets a point, returning `None` if the result falls outside the grid.
+    pub fn translate(&self, Point(x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let (xn, xo) = x.overflowing_add_signed(dx);
+        let (yn, yo) = y.overflowing_add_signed(dy);
+
+        if xo || xn >= self.width || yo || yn >= self.height {
+            None
+        } else {
+            Some(Point(xn, yn))
+        }
+    }
+
+    /// Points reached by repeatedly applying `step` to `start` (inclusive) until leaving the
+    /// grid.
+    pub fn ray(&self, start: Point, step: (isize, isize)) -> impl Iterator<Item = Point> + '_ {
+        let first = self.get(start).map(|_| start);
+        std::iter::successors(first, move |&p| self.translate(p, step))
+    }
+
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        DIRECTIONS_4
+            .iter()
+            .filter_map(move |&d| self.translate(point, d))
+    }
+
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        DIRECTIONS_8
+            .iter()
+            .filter_map(move |&d| self.translate(point, d))
+    }
+
+    /// All points in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Diagonals running down and to the right, starting from the bottom-left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| Point(0, y))
+            .chain((1..self.width).map(|x| Point(x, 0)));
+
+        starts.map(move |s| self.ray(s, (1, 1)).map(move |p| &self[p]))
+    }
+
+    /// Diagonals running down and to the left, starting from the top-left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        let starts = (0..self.width)
+            .map(|x| Point(x, 0))
+            .chain((1..self.height).map(|y| Point(self.width.saturating_sub(1), y)));
+
+        starts.map(move |s| self.ray(s, (-1, 1)).map(move |p| &self[p]))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |Point(x, y)| {
+            self[Point(y, x)].clone()
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |Point(x, y)| {
+            self[Point(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |Point(x, y)| {
+            self[Point(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |Point(x, y)| {
+            self[Point(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |Point(x, y)| {
+            self[Point(x, self.height - 1 - y)].clone()
+        })
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, Point(x, y): Point) -> &Self::Output {
+        assert!(x < self.width && y < self.height, "point out of bounds");
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, Point(x, y): Point) -> &mut Self::Output {
+        assert!(x < self.width && y < self.height, "point out of bounds");
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Three columns by two rows.
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef", |c| c).unwrap()
+    }
+
+    fn lines(grid: &Grid<char>) -> String {
+        grid.to_string()
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn rotates_and_flips_non_square_grids() {
+        let grid = grid();
+
+        assert_eq!(lines(&grid), "abc\ndef");
+        assert_eq!(lines(&grid.transpose()), "ad\nbe\ncf");
+        assert_eq!(lines(&grid.rotate_clockwise()), "da\neb\nfc");
+        assert_eq!(lines(&grid.rotate_counterclockwise()), "cf\nbe\nad");
+        assert_eq!(lines(&grid.flip_horizontal()), "cba\nfed");
+        assert_eq!(lines(&grid.flip_vertical()), "def\nabc");
+
+        let rotated = grid.rotate_clockwise();
+        assert_eq!((rotated.width(), rotated.height()), (2, 3));
+        assert_eq!(rotated.rotate_counterclockwise(), grid);
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+    }
+
+    #[test]
+    fn reads_rows_columns_and_diagonals() {
+        let grid = grid();
+
+        assert_eq!(collect(grid.rows()), ["abc", "def"]);
+        assert_eq!(collect(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+
+        // from the bottom-left corner, then along the top
+        assert_eq!(collect(grid.diagonals()), ["d", "ae", "bf", "c"]);
+        // along the top, then down the right side
+        assert_eq!(collect(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn translate_stops_at_the_edges() {
+        let grid = grid();
+
+        assert_eq!(grid.translate(Point(0, 0), (-1, 0)), None);
+        assert_eq!(grid.translate(Point(0, 0), (0, -1)), None);
+        assert_eq!(grid.translate(Point(2, 1), (1, 0)), None);
+        assert_eq!(grid.translate(Point(2, 1), (0, 1)), None);
+        assert_eq!(grid.translate(Point(0, 0), (2, 1)), Some(Point(2, 1)));
+        assert_eq!(grid.translate(Point(2, 1), (-2, -1)), Some(Point(0, 0)));
+        assert_eq!(grid.translate(Point(1, 1), (isize::MIN, 0)), None);
+
+        assert_eq!(
+            grid.ray(Point(0, 0), (1, 0)).collect::<Vec<_>>(),
+            [Point(0, 0), Point(1, 0), Point(2, 0)]
+        );
+        assert_eq!(grid.ray(Point(3, 0), (1, 0)).count(), 0);
+        assert_eq!(grid.neighbors8(Point(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors4(Point(1, 1)).count(), 3);
+    }
+
+    #[test]
+    fn reports_uneven_lines() {
+        let e = Grid::parse("abc\nabcd", |c| c).unwrap_err();
+
+        assert_eq!(
+            e,
+            ParseGridError {
+                line: 2,
+                expected: 3,
+                found: 4,
+            }
+        );
+        assert_eq!(e.column(), 4);
+    }
+}
@@ -0,0 +1,68 @@
+use std::str::FromStr;
+
+use crate::Error;
+
+/// Command-line arguments after the command name. Every flag takes a value.
+pub struct Args {
+    flags: Vec<(String, String)>,
+    positional: Vec<String>,
+}
+
+impl Args {
+    /// Parses flags only, rejecting any positional arguments.
+    pub fn parse(args: &[String], known: &[&str]) -> Result<Self, Error> {
+        let args = Args::parse_positional(args, known)?;
+
+        match args.positional.first() {
+            Some(arg) => Err(Error::Usage(format!("unknown argument {:?}", arg))),
+            None => Ok(args),
+        }
+    }
+
+    /// Parses flags, keeping any arguments that are not flags in order.
+    pub fn parse_positional(args: &[String], known: &[&str]) -> Result<Self, Error> {
+        let mut flags = vec![];
+        let mut positional = vec![];
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            if !arg.starts_with("--") {
+                positional.push(arg.to_owned());
+                continue;
+            }
+
+            if !known.contains(&arg.as_str()) {
+                return Err(Error::Usage(format!("unknown argument {:?}", arg)));
+            }
+
+            let value = args
+                .next()
+                .ok_or_else(|| Error::Usage(format!("missing value for {}", arg)))?;
+
+            flags.push((arg.to_owned(), value.to_owned()));
+        }
+
+        Ok(Args { flags, positional })
+    }
+
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+
+    pub fn value(&self, flag: &str) -> Option<&str> {
+        self.flags
+            .iter()
+            .rev()
+            .find(|(f, _)| f == flag)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn parse_value<T: FromStr>(&self, flag: &str) -> Result<Option<T>, Error> {
+        self.value(flag)
+            .map(|v| {
+                v.parse()
+                    .map_err(|_| Error::Usage(format!("invalid value {:?} for {}", v, flag)))
+            })
+            .transpose()
+    }
+}
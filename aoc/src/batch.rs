@@ -0,0 +1,176 @@
+use std::{
+    any::Any,
+    fmt::Write,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use aoc_core::{format_duration, Outcome, Part, Source};
+
+use crate::{args::Args, solvers, solvers::Solver, Error};
+
+struct Run {
+    file: String,
+    result: Result<Outcome, String>,
+}
+
+pub fn batch(args: &[String]) -> Result<(), Error> {
+    let args = Args::parse_positional(args, &["--day", "--jobs"])?;
+
+    let day = args
+        .parse_value("--day")?
+        .ok_or_else(|| Error::Usage("missing --day".to_owned()))?;
+    let jobs = match args.parse_value::<usize>("--jobs")? {
+        Some(0) => return Err(Error::Usage("--jobs must be at least 1".to_owned())),
+        Some(jobs) => jobs,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+
+    let dir = match args.positional() {
+        [dir] => PathBuf::from(dir),
+        [] => return Err(Error::Usage("missing input directory".to_owned())),
+        [_, extra, ..] => return Err(Error::Usage(format!("unknown argument {:?}", extra))),
+    };
+
+    let solver =
+        solvers::find(day).ok_or_else(|| Error::Usage(format!("no solution for day {}", day)))?;
+
+    let mut files = fs::read_dir(&dir)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|e| e.path()))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(|e| Error::Failed(format!("could not read {}: {}", dir.display(), e)))?;
+
+    files.retain(|path| path.is_file());
+    files.sort();
+
+    let runs = run_all(day, solver, &files, jobs);
+    let failures = runs.iter().filter(|run| run.result.is_err()).count();
+
+    print!("{}", to_table(&runs));
+
+    if failures > 0 {
+        return Err(Error::Failed(format!(
+            "{} of {} inputs failed",
+            failures,
+            runs.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Solves every file on `jobs` threads, keeping the results in file order.
+fn run_all(day: u8, solver: Solver, files: &[PathBuf], jobs: usize) -> Vec<Run> {
+    let next = AtomicUsize::new(0);
+
+    // panics are reported in the table rather than printed as they happen
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut runs: Vec<(usize, Run)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.min(files.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut runs = vec![];
+
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(path) = files.get(index) else {
+                            break runs;
+                        };
+
+                        runs.push((index, run_one(day, solver, path)));
+                    }
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+
+    panic::set_hook(hook);
+
+    runs.sort_by_key(|&(index, _)| index);
+    runs.into_iter().map(|(_, run)| run).collect()
+}
+
+fn run_one(day: u8, solver: Solver, path: &Path) -> Run {
+    let file = path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+
+    let result = Source::Path(path.to_owned())
+        .read(day)
+        .map_err(|e| e.to_string())
+        .and_then(|input| {
+            panic::catch_unwind(AssertUnwindSafe(|| solver(&input, &Part::ALL)))
+                .map_err(|payload| format!("panicked: {}", panic_message(payload)))?
+                .map_err(|e| e.to_string())
+        });
+
+    Run { file, result }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown cause".to_owned()
+    }
+}
+
+fn to_table(runs: &[Run]) -> String {
+    let width = runs
+        .iter()
+        .map(|run| run.file.chars().count())
+        .chain(["file".len()])
+        .max()
+        .unwrap_or(0);
+
+    let mut table = format!(
+        "{:<width$}  {:>16}  {:>16}  {:>10}  {:>10}  {:>10}\n",
+        "file", "part 1", "part 2", "parse", "part 1", "part 2"
+    );
+
+    for run in runs {
+        match &run.result {
+            Ok(outcome) => {
+                let answers = &outcome.answers;
+
+                writeln!(
+                    table,
+                    "{:<width$}  {:>16}  {:>16}  {:>10}  {:>10}  {:>10}",
+                    run.file,
+                    answers[0].answer.to_string(),
+                    answers[1].answer.to_string(),
+                    format_duration(outcome.parse_elapsed),
+                    format_duration(answers[0].elapsed),
+                    format_duration(answers[1].elapsed)
+                )
+                .unwrap();
+            }
+            // only the first line, since parse errors continue with the offending snippet
+            Err(e) => writeln!(
+                table,
+                "{:<width$}  failed: {}",
+                run.file,
+                e.lines().next().unwrap_or_default()
+            )
+            .unwrap(),
+        }
+    }
+
+    table
+}
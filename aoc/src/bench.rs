@@ -0,0 +1,368 @@
+use std::{collections::HashMap, fmt::Write, fs, str::FromStr, time::Duration};
+
+use aoc_core::{format_duration, Part, Source, INPUTS_DIR};
+
+use crate::{args::Args, solvers, Error};
+
+const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(()),
+        }
+    }
+}
+
+struct Measurement {
+    day: u8,
+    phase: &'static str,
+    runs: usize,
+    min: Duration,
+    median: Duration,
+    baseline: Option<Duration>,
+}
+
+impl Measurement {
+    fn new(day: u8, phase: &'static str, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Measurement {
+            day,
+            phase,
+            runs: samples.len(),
+            min: samples[0],
+            median,
+            baseline: None,
+        }
+    }
+
+    /// Change in median relative to the baseline, as a percentage.
+    fn change(&self) -> Option<f64> {
+        self.baseline.map(|baseline| {
+            (self.median.as_secs_f64() / baseline.as_secs_f64().max(f64::EPSILON) - 1.0) * 100.0
+        })
+    }
+}
+
+pub fn bench(args: &[String]) -> Result<(), Error> {
+    let args = Args::parse(
+        args,
+        &[
+            "--day",
+            "--input",
+            "--runs",
+            "--format",
+            "--baseline",
+            "--save-baseline",
+            "--threshold",
+        ],
+    )?;
+
+    let runs = args.parse_value("--runs")?.unwrap_or(10);
+    let format = args.parse_value("--format")?.unwrap_or(Format::Table);
+    let threshold = args.parse_value("--threshold")?.unwrap_or(10.0);
+
+    if runs == 0 {
+        return Err(Error::Usage("--runs must be at least 1".to_owned()));
+    }
+
+    let source = args
+        .value("--input")
+        .map_or_else(|| Source::Path(INPUTS_DIR.into()), Source::from_arg);
+
+    let days = match args.parse_value::<u8>("--day")? {
+        Some(day) => vec![day],
+        None if !matches!(&source, Source::Path(path) if path.is_dir()) => {
+            return Err(Error::Usage(
+                "--input must be a directory unless --day is given".to_owned(),
+            ));
+        }
+        None => solvers::SOLVERS.iter().map(|&(day, _)| day).collect(),
+    };
+
+    let mut measurements = vec![];
+
+    for day in days {
+        let solver = solvers::find(day)
+            .ok_or_else(|| Error::Usage(format!("no solution for day {}", day)))?;
+
+        let input = source.read(day).map_err(|e| Error::Failed(e.to_string()))?;
+
+        let mut samples: [Vec<Duration>; 3] = Default::default();
+
+        // the first run warms caches and is not measured
+        for run in 0..=runs {
+            let outcome = solver(&input, &Part::ALL)
+                .map_err(|e| Error::Failed(format!("day {}: {}", day, e)))?;
+
+            if run > 0 {
+                samples[0].push(outcome.parse_elapsed);
+
+                for (i, solved) in outcome.answers.iter().enumerate() {
+                    samples[i + 1].push(solved.elapsed);
+                }
+            }
+        }
+
+        for (phase, samples) in PHASES.into_iter().zip(samples) {
+            measurements.push(Measurement::new(day, phase, samples));
+        }
+    }
+
+    if let Some(path) = args.value("--baseline") {
+        let baseline = read_baseline(path)?;
+
+        for measurement in measurements.iter_mut() {
+            measurement.baseline = baseline.get(&(measurement.day, measurement.phase)).copied();
+        }
+    }
+
+    if let Some(path) = args.value("--save-baseline") {
+        fs::write(path, to_csv(&measurements))
+            .map_err(|e| Error::Failed(format!("could not write {}: {}", path, e)))?;
+    }
+
+    let report = match format {
+        Format::Table => to_table(&measurements, threshold),
+        Format::Json => to_json(&measurements, threshold),
+        Format::Csv => to_csv(&measurements),
+    };
+
+    print!("{}", report);
+
+    Ok(())
+}
+
+/// Reads median timings from a report previously written in CSV format.
+fn read_baseline(path: &str) -> Result<HashMap<(u8, &'static str), Duration>, Error> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| Error::Failed(format!("could not read {}: {}", path, e)))?;
+    let invalid = || Error::Failed(format!("{} is not a benchmark CSV report", path));
+
+    let mut lines = contents.lines();
+    let header: Vec<&str> = lines.next().ok_or_else(invalid)?.split(',').collect();
+    let column = |name| header.iter().position(|&h| h == name).ok_or_else(invalid);
+    let (day, phase, median) = (column("day")?, column("phase")?, column("median_ns")?);
+
+    let mut baseline = HashMap::new();
+
+    for line in lines.filter(|l| !l.is_empty()) {
+        let fields: Vec<&str> = line.split(',').collect();
+        let field = |i: usize| fields.get(i).copied().ok_or_else(invalid);
+
+        let day = field(day)?.parse().map_err(|_| invalid())?;
+        let phase = PHASES
+            .into_iter()
+            .find(|&p| p == field(phase).unwrap_or_default())
+            .ok_or_else(invalid)?;
+        let median = Duration::from_nanos(field(median)?.parse().map_err(|_| invalid())?);
+
+        baseline.insert((day, phase), median);
+    }
+
+    Ok(baseline)
+}
+
+fn to_table(measurements: &[Measurement], threshold: f64) -> String {
+    let mut table = format!(
+        "{:>3}  {:<5}  {:>4}  {:>10}  {:>10}  {:>10}  {:>8}\n",
+        "day", "phase", "runs", "min", "median", "baseline", "change"
+    );
+
+    for m in measurements {
+        let baseline = m.baseline.map_or("-".to_owned(), format_duration);
+        let change = m.change().map_or("-".to_owned(), |c| format!("{:+.1}%", c));
+        let flag = if m.change().is_some_and(|c| c > threshold) {
+            "  regressed"
+        } else {
+            ""
+        };
+
+        writeln!(
+            table,
+            "{:>3}  {:<5}  {:>4}  {:>10}  {:>10}  {:>10}  {:>8}{}",
+            m.day,
+            m.phase,
+            m.runs,
+            format_duration(m.min),
+            format_duration(m.median),
+            baseline,
+            change,
+            flag
+        )
+        .unwrap();
+    }
+
+    table
+}
+
+fn to_json(measurements: &[Measurement], threshold: f64) -> String {
+    let records: Vec<String> = measurements
+        .iter()
+        .map(|m| {
+            let mut record = format!(
+                "{{\"day\":{},\"phase\":\"{}\",\"runs\":{},\"min_ns\":{},\"median_ns\":{}",
+                m.day,
+                m.phase,
+                m.runs,
+                m.min.as_nanos(),
+                m.median.as_nanos()
+            );
+
+            if let (Some(baseline), Some(change)) = (m.baseline, m.change()) {
+                write!(
+                    record,
+                    ",\"baseline_median_ns\":{},\"change_percent\":{:.2},\"regressed\":{}",
+                    baseline.as_nanos(),
+                    change,
+                    change > threshold
+                )
+                .unwrap();
+            }
+
+            record.push('}');
+            record
+        })
+        .collect();
+
+    format!("[{}]\n", records.join(","))
+}
+
+fn to_csv(measurements: &[Measurement]) -> String {
+    let mut csv = "day,phase,runs,min_ns,median_ns,baseline_median_ns,change_percent\n".to_owned();
+
+    for m in measurements {
+        writeln!(
+            csv,
+            "{},{},{},{},{},{},{}",
+            m.day,
+            m.phase,
+            m.runs,
+            m.min.as_nanos(),
+            m.median.as_nanos(),
+            m.baseline
+                .map_or(String::new(), |b| b.as_nanos().to_string()),
+            m.change().map_or(String::new(), |c| format!("{:.2}", c))
+        )
+        .unwrap();
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    fn nanos(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    fn measurement(
+        day: u8,
+        phase: &'static str,
+        median: u64,
+        baseline: Option<u64>,
+    ) -> Measurement {
+        Measurement {
+            baseline: baseline.map(Duration::from_nanos),
+            ..Measurement::new(day, phase, nanos(&[median]))
+        }
+    }
+
+    #[test]
+    fn median_of_odd_sample_count_is_the_middle_sample() {
+        let m = Measurement::new(1, "parse", nanos(&[30, 10, 20]));
+
+        assert_eq!(m.runs, 3);
+        assert_eq!(m.min, Duration::from_nanos(10));
+        assert_eq!(m.median, Duration::from_nanos(20));
+    }
+
+    #[test]
+    fn median_of_even_sample_count_averages_the_middle_samples() {
+        let m = Measurement::new(1, "parse", nanos(&[40, 10, 30, 20]));
+
+        assert_eq!(m.runs, 4);
+        assert_eq!(m.min, Duration::from_nanos(10));
+        assert_eq!(m.median, Duration::from_nanos(25));
+
+        let single = Measurement::new(1, "parse", nanos(&[7]));
+        assert_eq!(single.median, Duration::from_nanos(7));
+    }
+
+    #[test]
+    fn csv_report_reads_back_as_a_baseline() {
+        let measurements = [
+            measurement(1, "parse", 1_500, None),
+            measurement(1, "part1", 2_000, Some(1_000)),
+            measurement(12, "part2", 123_456_789, None),
+        ];
+        let path = env::temp_dir().join(format!("aoc-bench-{}.csv", process::id()));
+
+        fs::write(&path, to_csv(&measurements)).unwrap();
+        let baseline = read_baseline(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+
+        let baseline = baseline.unwrap_or_else(|_| panic!("report did not read back"));
+
+        assert_eq!(baseline.len(), 3);
+        for m in &measurements {
+            assert_eq!(baseline.get(&(m.day, m.phase)), Some(&m.median));
+        }
+    }
+
+    #[test]
+    fn rejects_files_that_are_not_reports() {
+        let path = env::temp_dir().join(format!("aoc-bench-invalid-{}.csv", process::id()));
+
+        fs::write(&path, "day,phase,median_ns\n1,part3,100\n").unwrap();
+        let baseline = read_baseline(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+
+        assert!(baseline.is_err());
+    }
+
+    #[test]
+    fn flags_phases_slower_than_the_threshold() {
+        let measurements = [
+            measurement(1, "parse", 1_050, Some(1_000)),
+            measurement(1, "part1", 1_200, Some(1_000)),
+            measurement(1, "part2", 500, None),
+        ];
+
+        let table = to_table(&measurements, 10.0);
+        let flagged: Vec<_> = table.lines().map(|l| l.ends_with("regressed")).collect();
+        assert_eq!(flagged, [false, false, true, false]);
+
+        let json = to_json(&measurements, 10.0);
+        assert!(json.contains("\"phase\":\"parse\",\"runs\":1,\"min_ns\":1050,\"median_ns\":1050,\"baseline_median_ns\":1000,\"change_percent\":5.00,\"regressed\":false"));
+        assert!(json.contains("\"change_percent\":20.00,\"regressed\":true"));
+        assert!(!json.contains("\"median_ns\":500,\"baseline"));
+
+        // a higher threshold lets the same change through
+        assert!(!to_table(&measurements, 25.0).contains("regressed"));
+        assert!(!to_json(&measurements, 25.0).contains("\"regressed\":true"));
+    }
+}
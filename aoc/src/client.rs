@@ -0,0 +1,153 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    io::Read,
+    time::Duration,
+};
+
+use aoc_core::{format_duration, Part};
+
+use crate::{args::Args, Error};
+
+pub const YEAR: u16 = 2024;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/dstelljes/advent-of-code-2024";
+
+/// Talks to the Advent of Code site, or any server that mimics its endpoints.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+/// What the server said about a submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// The part has already been solved, so the answer was not checked.
+    AlreadySolved,
+    /// Submitted too soon after a previous attempt.
+    Wait(Duration),
+}
+
+impl Client {
+    /// Configures a client from `--base-url` (or `AOC_BASE_URL`) and the `AOC_SESSION` cookie.
+    pub fn from_args(args: &Args) -> Result<Self, Error> {
+        let base_url = args
+            .value("--base-url")
+            .map(str::to_owned)
+            .or_else(|| env::var("AOC_BASE_URL").ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_owned());
+
+        let session = env::var("AOC_SESSION")
+            .map_err(|_| Error::Usage("AOC_SESSION must be set to a session cookie".to_owned()))?;
+
+        Ok(Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+        })
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call();
+
+        read_body(&url, response)
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, Error> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let body = read_body(&url, response)?;
+
+        parse_verdict(&body)
+            .ok_or_else(|| Error::Failed(format!("unrecognized response from {}", url)))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Wait(wait) => {
+                write!(f, "answered too recently; wait {}", format_duration(*wait))
+            }
+        }
+    }
+}
+
+fn read_body(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, Error> {
+    let response = response.map_err(|e| match e {
+        ureq::Error::Status(code, _) => Error::Failed(format!("{} returned {}", url, code)),
+        ureq::Error::Transport(e) => Error::Failed(format!("could not reach {}: {}", url, e)),
+    })?;
+
+    let mut body = String::new();
+    response
+        .into_reader()
+        .read_to_string(&mut body)
+        .map_err(|e| Error::Failed(format!("could not read response from {}: {}", url, e)))?;
+
+    Ok(body)
+}
+
+fn parse_verdict(body: &str) -> Option<Verdict> {
+    if body.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    } else if body.contains("That's not the right answer") {
+        Some(if body.contains("too high") {
+            Verdict::TooHigh
+        } else if body.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        })
+    } else if body.contains("You don't seem to be solving the right level") {
+        Some(Verdict::AlreadySolved)
+    } else if body.contains("You gave an answer too recently") {
+        Some(Verdict::Wait(
+            parse_wait(body).unwrap_or(Duration::from_secs(60)),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Reads the remaining wait from e.g. "You have 1m 5s left to wait."
+fn parse_wait(body: &str) -> Option<Duration> {
+    let before = &body[..body.find(" left to wait")?];
+    let start = before.rfind("have ")? + "have ".len();
+
+    before[start..]
+        .split_whitespace()
+        .map(|unit| {
+            if let Some(minutes) = unit.strip_suffix('m') {
+                minutes.parse::<u64>().ok().map(|m| m * 60)
+            } else {
+                unit.strip_suffix('s')?.parse().ok()
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
@@ -0,0 +1,35 @@
+use std::fs;
+
+use aoc_core::{default_path, INPUTS_DIR};
+
+use crate::{args::Args, client::Client, Error};
+
+pub fn fetch(args: &[String]) -> Result<(), Error> {
+    let args = Args::parse(args, &["--day", "--base-url"])?;
+
+    let day: u8 = args
+        .parse_value("--day")?
+        .ok_or_else(|| Error::Usage("missing --day".to_owned()))?;
+
+    if !(1..=25).contains(&day) {
+        return Err(Error::Usage(format!("no puzzle for day {}", day)));
+    }
+
+    // inputs never change, so a cached copy is never refreshed
+    let path = default_path(day);
+
+    if path.exists() {
+        eprintln!("{} already exists", path.display());
+        return Ok(());
+    }
+
+    let input = Client::from_args(&args)?.fetch_input(day)?;
+
+    fs::create_dir_all(INPUTS_DIR)
+        .and_then(|_| fs::write(&path, input))
+        .map_err(|e| Error::Failed(format!("could not write {}: {}", path.display(), e)))?;
+
+    eprintln!("saved {}", path.display());
+
+    Ok(())
+}
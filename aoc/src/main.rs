@@ -0,0 +1,49 @@
+use std::{env, process::exit};
+
+mod args;
+mod batch;
+mod bench;
+mod client;
+mod fetch;
+mod run;
+mod solvers;
+mod submit;
+
+const USAGE: &str = "\
+usage: aoc run --day <n> [--part <1|2>] [--input <path|->] [--format <plain|labelled|json>]
+       aoc bench [--day <n>] [--input <path>] [--runs <n>] [--format <table|json|csv>]
+                 [--baseline <csv>] [--save-baseline <csv>] [--threshold <percent>]
+       aoc batch --day <n> [--jobs <n>] <dir>
+       aoc fetch --day <n> [--base-url <url>]
+       aoc submit --day <n> --part <1|2> [--answer <answer> | --input <path|->] [--base-url <url>]";
+
+pub enum Error {
+    Usage(String),
+    Failed(String),
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run::run(&args[1..]),
+        Some("bench") => bench::bench(&args[1..]),
+        Some("batch") => batch::batch(&args[1..]),
+        Some("fetch") => fetch::fetch(&args[1..]),
+        Some("submit") => submit::submit(&args[1..]),
+        Some(command) => Err(Error::Usage(format!("unknown command {:?}", command))),
+        None => Err(Error::Usage("missing command".to_owned())),
+    };
+
+    match result {
+        Ok(()) => {}
+        Err(Error::Usage(message)) => {
+            eprintln!("{}\n{}", message, USAGE);
+            exit(2);
+        }
+        Err(Error::Failed(message)) => {
+            eprintln!("{}", message);
+            exit(1);
+        }
+    }
+}
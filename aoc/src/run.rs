@@ -0,0 +1,32 @@
+use aoc_core::{format_answer, Format, Part, Source};
+
+use crate::{args::Args, solvers, Error};
+
+pub fn run(args: &[String]) -> Result<(), Error> {
+    let args = Args::parse(args, &["--day", "--part", "--input", "--format"])?;
+
+    let day = args
+        .parse_value("--day")?
+        .ok_or_else(|| Error::Usage("missing --day".to_owned()))?;
+    let format = args.parse_value::<Format>("--format")?.unwrap_or_default();
+    let parts = match args.parse_value::<Part>("--part")? {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let solver =
+        solvers::find(day).ok_or_else(|| Error::Usage(format!("no solution for day {}", day)))?;
+
+    let source = args
+        .value("--input")
+        .map_or_else(|| Source::detect(day), Source::from_arg);
+    let input = source.read(day).map_err(|e| Error::Failed(e.to_string()))?;
+
+    let outcome = solver(&input, &parts).map_err(|e| Error::Failed(e.to_string()))?;
+
+    for solved in outcome.answers {
+        println!("{}", format_answer(day, &solved, format));
+    }
+
+    Ok(())
+}
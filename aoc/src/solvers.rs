@@ -0,0 +1,24 @@
+use aoc_core::{solve, Outcome, Part, Result, Solution};
+
+pub type Solver = fn(&str, &[Part]) -> Result<Outcome>;
+
+pub static SOLVERS: [(u8, Solver); 14] = [
+    (day_1::Day1::DAY, solve::<day_1::Day1>),
+    (day_2::Day2::DAY, solve::<day_2::Day2>),
+    (day_3::Day3::DAY, solve::<day_3::Day3>),
+    (day_4::Day4::DAY, solve::<day_4::Day4>),
+    (day_5::Day5::DAY, solve::<day_5::Day5>),
+    (day_6::Day6::DAY, solve::<day_6::Day6>),
+    (day_7::Day7::DAY, solve::<day_7::Day7>),
+    (day_8::Day8::DAY, solve::<day_8::Day8>),
+    (day_9::Day9::DAY, solve::<day_9::Day9>),
+    (day_10::Day10::DAY, solve::<day_10::Day10>),
+    (day_11::Day11::DAY, solve::<day_11::Day11>),
+    (day_12::Day12::DAY, solve::<day_12::Day12>),
+    (day_13::Day13::DAY, solve::<day_13::Day13>),
+    (day_14::Day14::DAY, solve::<day_14::Day14>),
+];
+
+pub fn find(day: u8) -> Option<Solver> {
+    SOLVERS.iter().find(|&&(d, _)| d == day).map(|&(_, s)| s)
+}
@@ -0,0 +1,244 @@
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::{default_path, format_duration, Part, Source};
+
+use crate::{
+    args::Args,
+    client::{Client, Verdict},
+    solvers, Error,
+};
+
+/// Every answer sent so far and what the server said, so that nothing is sent twice.
+const HISTORY_PATH: &str = ".aoc/submissions.txt";
+
+struct Submission {
+    day: u8,
+    part: Part,
+    answer: String,
+    verdict: Verdict,
+}
+
+#[derive(Default)]
+struct History {
+    submissions: Vec<Submission>,
+    /// Seconds since the epoch before which the server will refuse another answer.
+    wait_until: u64,
+}
+
+pub fn submit(args: &[String]) -> Result<(), Error> {
+    let args = Args::parse(
+        args,
+        &["--day", "--part", "--answer", "--input", "--base-url"],
+    )?;
+
+    let day = args
+        .parse_value("--day")?
+        .ok_or_else(|| Error::Usage("missing --day".to_owned()))?;
+    let part = args
+        .parse_value("--part")?
+        .ok_or_else(|| Error::Usage("missing --part".to_owned()))?;
+
+    let answer = match args.value("--answer") {
+        Some(answer) => answer.to_owned(),
+        None => solve(day, part, args.value("--input"))?,
+    };
+
+    let path = Path::new(HISTORY_PATH);
+    let mut history = History::read(path)?;
+
+    if let Some(known) = history.check(day, part, &answer)? {
+        println!("{}", known);
+        return Ok(());
+    }
+
+    let verdict = Client::from_args(&args)?.submit(day, part, &answer)?;
+
+    if let Verdict::Wait(wait) = verdict {
+        history.wait_until = now() + wait.as_secs();
+        history.write(path)?;
+
+        return Err(Error::Failed(verdict.to_string()));
+    }
+
+    history.submissions.push(Submission {
+        day,
+        part,
+        answer: answer.clone(),
+        verdict,
+    });
+    history.write(path)?;
+
+    println!("{}: {}", answer, verdict);
+
+    Ok(())
+}
+
+fn solve(day: u8, part: Part, input: Option<&str>) -> Result<String, Error> {
+    let solver =
+        solvers::find(day).ok_or_else(|| Error::Usage(format!("no solution for day {}", day)))?;
+
+    // never guess at stdin here, since a wrong answer posted cannot be taken back
+    let source = input.map_or_else(|| Source::Path(default_path(day)), Source::from_arg);
+    let input = source.read(day).map_err(|e| Error::Failed(e.to_string()))?;
+
+    if input.trim().is_empty() {
+        return Err(Error::Failed(format!(
+            "refusing to submit an answer for empty input from {}",
+            source.resolve(day)
+        )));
+    }
+
+    let outcome = solver(&input, &[part]).map_err(|e| Error::Failed(e.to_string()))?;
+
+    Ok(outcome.answers[0].answer.to_string())
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+impl History {
+    fn read(path: &Path) -> Result<Self, Error> {
+        let mut history = History::default();
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(_) if !path.exists() => return Ok(history),
+            Err(e) => {
+                return Err(Error::Failed(format!(
+                    "could not read {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+
+        let invalid =
+            |line: &str| Error::Failed(format!("invalid line in {}: {}", path.display(), line));
+
+        for line in contents.lines() {
+            let fields: Vec<_> = line.splitn(4, ' ').collect();
+
+            match fields[..] {
+                ["wait", until] => {
+                    history.wait_until = until.parse().map_err(|_| invalid(line))?;
+                }
+                [day, part, verdict, answer] => history.submissions.push(Submission {
+                    day: day.parse().map_err(|_| invalid(line))?,
+                    part: part.parse().map_err(|_| invalid(line))?,
+                    answer: answer.to_owned(),
+                    verdict: parse_verdict(verdict).ok_or_else(|| invalid(line))?,
+                }),
+                _ => return Err(invalid(line)),
+            }
+        }
+
+        Ok(history)
+    }
+
+    fn write(&self, path: &Path) -> Result<(), Error> {
+        let mut contents = format!("wait {}\n", self.wait_until);
+
+        for s in &self.submissions {
+            contents += &format!(
+                "{} {} {} {}\n",
+                s.day,
+                s.part,
+                verdict_key(s.verdict),
+                s.answer
+            );
+        }
+
+        path.parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(path, contents))
+            .map_err(|e| Error::Failed(format!("could not write {}: {}", path.display(), e)))
+    }
+
+    /// Answers from history when the server does not need to be asked, or an error if it cannot
+    /// be asked yet.
+    fn check(&self, day: u8, part: Part, answer: &str) -> Result<Option<String>, Error> {
+        let previous: Vec<_> = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part)
+            .collect();
+
+        if let Some(solved) = previous
+            .iter()
+            .find(|s| matches!(s.verdict, Verdict::Correct | Verdict::AlreadySolved))
+        {
+            let mut message = format!("day {} part {} is already solved", day, part);
+
+            if solved.verdict == Verdict::Correct {
+                message += &format!(" with {}", solved.answer);
+            }
+
+            return Ok(Some(message));
+        }
+
+        if let Some(same) = previous.iter().find(|s| s.answer == answer) {
+            return Ok(Some(format!(
+                "{}: {} (already submitted)",
+                answer, same.verdict
+            )));
+        }
+
+        // a guess past a known bound is wrong without asking
+        if let Ok(n) = answer.parse::<i128>() {
+            for s in &previous {
+                let Ok(m) = s.answer.parse::<i128>() else {
+                    continue;
+                };
+
+                if (s.verdict == Verdict::TooHigh && n >= m)
+                    || (s.verdict == Verdict::TooLow && n <= m)
+                {
+                    return Ok(Some(format!(
+                        "{}: {} ({} was already {})",
+                        answer, s.verdict, m, s.verdict
+                    )));
+                }
+            }
+        }
+
+        let remaining = self.wait_until.saturating_sub(now());
+
+        if remaining > 0 {
+            return Err(Error::Failed(format!(
+                "answered too recently; wait {} before submitting again",
+                format_duration(Duration::from_secs(remaining))
+            )));
+        }
+
+        Ok(None)
+    }
+}
+
+fn verdict_key(verdict: Verdict) -> &'static str {
+    match verdict {
+        Verdict::Correct => "correct",
+        Verdict::Incorrect => "incorrect",
+        Verdict::TooHigh => "too-high",
+        Verdict::TooLow => "too-low",
+        Verdict::AlreadySolved => "already-solved",
+        Verdict::Wait(_) => "wait",
+    }
+}
+
+fn parse_verdict(key: &str) -> Option<Verdict> {
+    match key {
+        "correct" => Some(Verdict::Correct),
+        "incorrect" => Some(Verdict::Incorrect),
+        "too-high" => Some(Verdict::TooHigh),
+        "too-low" => Some(Verdict::TooLow),
+        "already-solved" => Some(Verdict::AlreadySolved),
+        _ => None,
+    }
+}
@@ -0,0 +1,43 @@
+use std::{env, fs, process::Command};
+
+#[test]
+fn batch_reports_answers_and_failures() {
+    let dir = env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+
+    let examples = concat!(env!("CARGO_MANIFEST_DIR"), "/../day-13/examples");
+    fs::copy(format!("{}/sample.txt", examples), dir.join("sample.txt")).unwrap();
+    fs::write(dir.join("broken.txt"), "Button A: X+1\n").unwrap();
+    fs::write(
+        dir.join("zero.txt"),
+        "Button A: X+0, Y+0\nButton B: X+0, Y+0\nPrize: X=1, Y=1\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["batch", "--day", "13", "--jobs", "2"])
+        .arg(&dir)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let rows: Vec<Vec<&str>> = stdout
+        .lines()
+        .skip(1)
+        .map(|line| line.split_whitespace().take(3).collect())
+        .collect();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        rows,
+        [
+            vec!["broken.txt", "failed:", "line"],
+            vec!["sample.txt", "480", "875318608908"],
+            vec!["zero.txt", "failed:", "panicked:"],
+        ]
+    );
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("2 of 3 inputs failed"));
+}
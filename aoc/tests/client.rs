@@ -0,0 +1,170 @@
+use std::{
+    env, fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    path::PathBuf,
+    process::{Command, Output},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// A stand-in for the puzzle site that records each request and answers it with `respond`.
+struct Server {
+    url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl Server {
+    fn start(respond: fn(&str) -> &'static str) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let log = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request = String::new();
+                let mut length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+
+                    if line == "\r\n" {
+                        break;
+                    }
+
+                    request += &line;
+                }
+
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                request += &String::from_utf8(body).unwrap();
+
+                let body = respond(&request);
+                log.lock().unwrap().push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        Server { url, requests }
+    }
+
+    fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn workspace(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn aoc(dir: &PathBuf, server: &Server, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .args(["--base-url", &server.url])
+        .current_dir(dir)
+        .env("AOC_SESSION", "cookie")
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: Output) -> String {
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn fetch_caches_input() {
+    let server = Server::start(|_| "1 2\n3 4\n");
+    let dir = workspace("fetch");
+
+    for _ in 0..2 {
+        assert!(aoc(&dir, &server, &["fetch", "--day", "1"])
+            .status
+            .success());
+    }
+
+    let requests = server.requests();
+
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2024/day/1/input "));
+    assert!(requests[0].contains("session=cookie"));
+    assert_eq!(
+        fs::read_to_string(dir.join("inputs/day-1.txt")).unwrap(),
+        "1 2\n3 4\n"
+    );
+}
+
+#[test]
+fn submit_never_repeats_an_answer() {
+    let server = Server::start(|request| {
+        if request.ends_with("answer=7") {
+            "<p>That's the right answer!</p>"
+        } else {
+            "<p>That's not the right answer; your answer is too high.</p>"
+        }
+    });
+    let dir = workspace("submit");
+    let submit = |answer| {
+        stdout(aoc(
+            &dir,
+            &server,
+            &["submit", "--day", "1", "--part", "2", "--answer", answer],
+        ))
+    };
+
+    assert_eq!(submit("42"), "42: too high\n");
+    assert_eq!(submit("42"), "42: too high (already submitted)\n");
+    assert_eq!(submit("50"), "50: too high (42 was already too high)\n");
+    assert_eq!(submit("7"), "7: correct\n");
+    assert_eq!(submit("8"), "day 1 part 2 is already solved with 7\n");
+
+    let requests = server.requests();
+
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("POST /2024/day/1/answer "));
+    assert!(requests[0].ends_with("level=2&answer=42"));
+}
+
+#[test]
+fn submit_solves_the_input_file_and_refuses_empty_input() {
+    let server = Server::start(|_| "<p>That's the right answer!</p>");
+    let dir = workspace("submit-input");
+    let submit = || aoc(&dir, &server, &["submit", "--day", "1", "--part", "1"]);
+
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    fs::write(dir.join("inputs/day-1.txt"), "\n").unwrap();
+
+    let output = submit();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("empty input"));
+    assert!(server.requests().is_empty());
+
+    fs::write(
+        dir.join("inputs/day-1.txt"),
+        "3 4\n4 3\n2 5\n1 3\n3 9\n3 3\n",
+    )
+    .unwrap();
+
+    assert_eq!(stdout(submit()), "11: correct\n");
+    assert!(server.requests()[0].ends_with("level=1&answer=11"));
+}
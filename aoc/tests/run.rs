@@ -0,0 +1,45 @@
+use std::{
+    env, fs,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+#[test]
+fn empty_stdin_falls_back_to_the_inputs_directory() {
+    let dir = env::temp_dir().join(format!("aoc-run-{}", std::process::id()));
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    fs::write(
+        dir.join("inputs/day-1.txt"),
+        "3 4\n4 3\n2 5\n1 3\n3 9\n3 3\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "1"])
+        .current_dir(&dir)
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "11\n31\n");
+}
+
+#[test]
+fn piped_stdin_is_read() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "1"])
+        .current_dir(env::temp_dir())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child.stdin.take().unwrap().write_all(b"1 2\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "1\n0\n");
+}
@@ -0,0 +1,236 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, ErrorKind, Read, Write},
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::in_range;
+
+/// Ids held in memory per column before they are sorted and spilled to a temporary file.
+pub const DEFAULT_RUN_SIZE: usize = 1 << 20;
+
+/// Runs merged at once, to stay within limits on open files.
+const MAX_FAN_IN: usize = 64;
+
+/// Sorted runs of ids spilled to temporary files, which are removed when dropped.
+struct Runs {
+    paths: Vec<PathBuf>,
+}
+
+/// Merges sorted runs into a single sorted stream of ids.
+struct Merge {
+    readers: Vec<BufReader<File>>,
+    heap: BinaryHeap<Reverse<(i128, usize)>>,
+}
+
+/// Computes the distance and similarity score of a two-column list of ids, as [`crate::part1`]
+/// and [`crate::part2`] do, while holding at most `run_size` ids per column in memory.
+pub fn solve_external(reader: impl BufRead, run_size: usize) -> io::Result<(u128, i128)> {
+    let (left, right) = spill(reader, run_size.max(1))?;
+
+    let distance = distance(&mut left.merge()?, &mut right.merge()?)?;
+    let similarity = similarity(&mut left.merge()?, &mut right.merge()?)?;
+
+    Ok((distance, similarity))
+}
+
+fn spill(reader: impl BufRead, run_size: usize) -> io::Result<(Runs, Runs)> {
+    let mut runs = (Runs { paths: vec![] }, Runs { paths: vec![] });
+    let mut buffers = (Vec::with_capacity(run_size), Vec::with_capacity(run_size));
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim_start_matches('\u{feff}');
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (a, b) = parse_pair(line).ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("line {}: expected two ids", index + 1),
+            )
+        })?;
+
+        buffers.0.push(a);
+        buffers.1.push(b);
+
+        if buffers.0.len() == run_size {
+            runs.0.write(&mut buffers.0)?;
+            runs.1.write(&mut buffers.1)?;
+        }
+    }
+
+    runs.0.write(&mut buffers.0)?;
+    runs.1.write(&mut buffers.1)?;
+
+    runs.0.compact()?;
+    runs.1.compact()?;
+
+    Ok(runs)
+}
+
+fn parse_pair(line: &str) -> Option<(i128, i128)> {
+    let mut ids = line.split_whitespace().map(str::parse);
+
+    match (ids.next(), ids.next(), ids.next()) {
+        (Some(Ok(a)), Some(Ok(b)), None) if in_range(&a) && in_range(&b) => Some((a, b)),
+        _ => None,
+    }
+}
+
+fn distance(left: &mut Merge, right: &mut Merge) -> io::Result<u128> {
+    let mut total = 0;
+
+    while let (Some(a), Some(b)) = (left.next()?, right.next()?) {
+        total += a.abs_diff(b);
+    }
+
+    Ok(total)
+}
+
+/// Joins the two sorted streams, counting each id's occurrences on both sides.
+fn similarity(left: &mut Merge, right: &mut Merge) -> io::Result<i128> {
+    let mut total = 0;
+    let mut a = left.next()?;
+    let mut b = right.next()?;
+
+    while let Some(id) = a {
+        let mut left_count = 0;
+
+        while a == Some(id) {
+            left_count += 1;
+            a = left.next()?;
+        }
+
+        while b.is_some_and(|b| b < id) {
+            b = right.next()?;
+        }
+
+        let mut right_count = 0;
+
+        while b == Some(id) {
+            right_count += 1;
+            b = right.next()?;
+        }
+
+        total += id * left_count * right_count;
+    }
+
+    Ok(total)
+}
+
+impl Runs {
+    fn write(&mut self, ids: &mut Vec<i128>) -> io::Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        ids.sort_unstable();
+
+        let mut writer = self.create()?;
+
+        for id in ids.drain(..) {
+            writer.write_all(&id.to_le_bytes())?;
+        }
+
+        writer.flush()
+    }
+
+    /// Merges runs together until few enough remain to be merged in one pass.
+    fn compact(&mut self) -> io::Result<()> {
+        while self.paths.len() > MAX_FAN_IN {
+            let batch: Vec<_> = self.paths.drain(..MAX_FAN_IN).collect();
+            let mut merge = Merge::open(&batch)?;
+            let mut writer = self.create()?;
+
+            while let Some(id) = merge.next()? {
+                writer.write_all(&id.to_le_bytes())?;
+            }
+
+            writer.flush()?;
+
+            for path in batch {
+                fs::remove_file(path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn merge(&self) -> io::Result<Merge> {
+        Merge::open(&self.paths)
+    }
+
+    fn create(&mut self) -> io::Result<BufWriter<File>> {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+        let path = env::temp_dir().join(format!(
+            "day-1-{}-{}.run",
+            process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let file = File::create(&path)?;
+        self.paths.push(path);
+
+        Ok(BufWriter::new(file))
+    }
+}
+
+impl Drop for Runs {
+    fn drop(&mut self) {
+        for path in &self.paths {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+impl Merge {
+    fn open(paths: &[PathBuf]) -> io::Result<Self> {
+        let mut merge = Merge {
+            readers: vec![],
+            heap: BinaryHeap::new(),
+        };
+
+        for (index, path) in paths.iter().enumerate() {
+            let mut reader = BufReader::new(File::open(path)?);
+
+            if let Some(id) = read_id(&mut reader)? {
+                merge.heap.push(Reverse((id, index)));
+            }
+
+            merge.readers.push(reader);
+        }
+
+        Ok(merge)
+    }
+
+    fn next(&mut self) -> io::Result<Option<i128>> {
+        let Some(Reverse((id, index))) = self.heap.pop() else {
+            return Ok(None);
+        };
+
+        if let Some(next) = read_id(&mut self.readers[index])? {
+            self.heap.push(Reverse((next, index)));
+        }
+
+        Ok(Some(id))
+    }
+}
+
+fn read_id(reader: &mut impl Read) -> io::Result<Option<i128>> {
+    let mut bytes = [0; 16];
+
+    match reader.read_exact(&mut bytes) {
+        Ok(()) => Ok(Some(i128::from_le_bytes(bytes))),
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::iter::zip;
+
+use aoc_core::{parse_lines, Answer, IResult, ParseError, Result, Solution};
+use nom::{
+    character::complete::{i128, space1},
+    combinator::verify,
+    error::context,
+    multi::separated_list1,
+};
+
+mod external;
+
+pub use external::{solve_external, DEFAULT_RUN_SIZE};
+
+/// Lists of ids, one per column of the input. All columns have the same length.
+/// Ids are signed or unsigned 64-bit integers, so they are kept as `i128` to hold either.
+pub type Input = Vec<Vec<i128>>;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    let rows = parse_lines(input, parse_line)?;
+    let width = rows.first().map_or(2, Vec::len);
+
+    if width < 2 {
+        return Err(ParseError::at_line(input, 1, 1, "at least 2 columns"));
+    }
+
+    let mut columns = vec![Vec::with_capacity(rows.len()); width];
+
+    for (index, row) in rows.into_iter().enumerate() {
+        if row.len() != width {
+            let column = input
+                .lines()
+                .nth(index)
+                .map_or(1, |line| line.chars().count() + 1);
+            return Err(ParseError::at_line(
+                input,
+                index + 1,
+                column,
+                format!("{} columns", width),
+            ));
+        }
+
+        for (column, id) in zip(columns.iter_mut(), row) {
+            column.push(id);
+        }
+    }
+
+    Ok(columns)
+}
+
+pub fn part1(lists: &Input) -> Answer {
+    distance(&lists[0], &lists[1]).into()
+}
+
+pub fn part2(lists: &Input) -> Answer {
+    similarity(&lists[0], &lists[1]).into()
+}
+
+/// Total distance between the lists once both are sorted.
+pub fn distance(left: &[i128], right: &[i128]) -> u128 {
+    sorted_pairs(left, right).map(|(a, b)| a.abs_diff(b)).sum()
+}
+
+/// Sum of each id in the left list multiplied by the number of times it appears in the right.
+pub fn similarity(left: &[i128], right: &[i128]) -> i128 {
+    let occurrence_counts = count(right);
+
+    left.iter()
+        .map(|id| *id * occurrence_counts.get(id).copied().unwrap_or(0) as i128)
+        .sum()
+}
+
+/// Distance between every pair of columns, indexed by column.
+pub fn distance_matrix(lists: &Input) -> Vec<Vec<u128>> {
+    lists
+        .iter()
+        .map(|a| lists.iter().map(|b| distance(a, b)).collect())
+        .collect()
+}
+
+/// Similarity of every pair of columns, with the row as the left list and the column as the
+/// right.
+pub fn similarity_matrix(lists: &Input) -> Vec<Vec<i128>> {
+    lists
+        .iter()
+        .map(|a| lists.iter().map(|b| similarity(a, b)).collect())
+        .collect()
+}
+
+/// Lists how each sorted pair contributes to the distance and each id to the similarity score
+/// of the first two columns, along with the matrices when there are more columns.
+pub fn explain(lists: &Input) -> String {
+    let mut report = String::new();
+
+    if lists.len() > 2 {
+        write_matrix(&mut report, "distance", &distance_matrix(lists));
+        write_matrix(&mut report, "similarity", &similarity_matrix(lists));
+    }
+
+    let (left, right) = (&lists[0], &lists[1]);
+
+    writeln!(report, "distance between sorted pairs").unwrap();
+    writeln!(
+        report,
+        "{:>12}  {:>12}  {:>12}",
+        "left", "right", "distance"
+    )
+    .unwrap();
+
+    for (a, b) in sorted_pairs(left, right) {
+        writeln!(report, "{:>12}  {:>12}  {:>12}", a, b, a.abs_diff(b)).unwrap();
+    }
+
+    writeln!(
+        report,
+        "{:>40}\n",
+        format!("total {}", distance(left, right))
+    )
+    .unwrap();
+
+    let left_counts = count(left);
+    let right_counts = count(right);
+
+    // largest contributions first, since those are the ones worth reconciling
+    let mut contributions: Vec<_> = left_counts
+        .iter()
+        .map(|(&id, &l)| {
+            let r = right_counts.get(&id).copied().unwrap_or(0);
+            (id, l, r, id * l as i128 * r as i128)
+        })
+        .collect();
+    contributions.sort_by_key(|&(id, _, _, score)| (std::cmp::Reverse(score.abs()), id));
+
+    writeln!(report, "similarity by id").unwrap();
+    writeln!(
+        report,
+        "{:>12}  {:>6}  {:>6}  {:>12}",
+        "id", "left", "right", "score"
+    )
+    .unwrap();
+
+    for (id, l, r, score) in contributions {
+        writeln!(report, "{:>12}  {:>6}  {:>6}  {:>12}", id, l, r, score).unwrap();
+    }
+
+    writeln!(
+        report,
+        "{:>42}",
+        format!("total {}", similarity(left, right))
+    )
+    .unwrap();
+
+    report
+}
+
+fn sorted_pairs(left: &[i128], right: &[i128]) -> impl Iterator<Item = (i128, i128)> {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+
+    left.sort();
+    right.sort();
+
+    zip(left, right)
+}
+
+fn count(ids: &[i128]) -> HashMap<i128, usize> {
+    ids.iter().fold(HashMap::new(), |mut acc, &id| {
+        *acc.entry(id).or_insert(0) += 1;
+        acc
+    })
+}
+
+fn write_matrix<T: std::fmt::Display>(report: &mut String, name: &str, matrix: &[Vec<T>]) {
+    writeln!(report, "{} matrix", name).unwrap();
+    write!(report, "{:>4}", "").unwrap();
+
+    for column in 1..=matrix.len() {
+        write!(report, "  {:>12}", column).unwrap();
+    }
+
+    for (row, values) in matrix.iter().enumerate() {
+        write!(report, "\n{:>4}", row + 1).unwrap();
+
+        for value in values {
+            write!(report, "  {:>12}", value).unwrap();
+        }
+    }
+
+    writeln!(report, "\n").unwrap();
+}
+
+/// Whether an id fits in a signed or unsigned 64-bit integer.
+pub(crate) fn in_range(id: &i128) -> bool {
+    (i64::MIN as i128..=u64::MAX as i128).contains(id)
+}
+
+fn parse_line(i: &str) -> IResult<'_, Vec<i128>> {
+    separated_list1(
+        space1,
+        context("id that fits in 64 bits", verify(i128, in_range)),
+    )(i)
+}
@@ -1,39 +1,49 @@
-use std::collections::HashMap;
-use std::io::stdin;
-use std::iter::zip;
+use std::{env, process::exit};
 
-use nom::character::complete::{space1, u32};
-use nom::sequence::separated_pair;
-use nom::IResult;
+use aoc_core::{Solution, Source};
+use day_1::{solve_external, Day1, DEFAULT_RUN_SIZE};
 
 fn main() {
-    let (mut firsts, mut seconds): (Vec<u32>, Vec<u32>) = stdin()
-        .lines()
-        .map(|l| parse_line(&l.unwrap()).expect("malformed input").1)
-        .unzip();
-
-    // part 1
-    firsts.sort();
-    seconds.sort();
-
-    let distance_between_sorteds: u32 = zip(firsts.iter(), seconds.iter())
-        .map(|(&a, &b)| a.abs_diff(b))
-        .sum();
-    println!("{}", distance_between_sorteds);
-
-    // part 2
-    let occurrence_counts = seconds.iter().fold(HashMap::new(), |mut acc, &id| {
-        *acc.entry(id).or_insert(0) += 1;
-        acc
-    });
-
-    let similarity_score: u32 = firsts
-        .iter()
-        .map(|&id| occurrence_counts.get(&id).map_or(0, |&count| count * id))
-        .sum();
-    println!("{}", similarity_score);
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.iter().any(|arg| arg == "--external") {
+        external(&args);
+    } else {
+        aoc_core::main_with::<Day1>(&[("--explain", day_1::explain)]);
+    }
 }
 
-fn parse_line(i: &str) -> IResult<&str, (u32, u32)> {
-    separated_pair(u32, space1, u32)(i)
+/// Solves with bounded memory, sorting on disk instead of in memory.
+fn external(args: &[String]) {
+    let usage = || -> ! {
+        eprintln!("usage: day-1 --external [--input <path|->] [--run-size <ids>]");
+        exit(2);
+    };
+
+    let mut source = Source::detect(Day1::DAY);
+    let mut run_size = DEFAULT_RUN_SIZE;
+    let mut args = args.iter().filter(|&arg| arg != "--external");
+
+    while let Some(arg) = args.next() {
+        let value = args.next().unwrap_or_else(|| usage());
+
+        match arg.as_str() {
+            "--input" => source = Source::from_arg(value),
+            "--run-size" => run_size = value.parse().unwrap_or_else(|_| usage()),
+            _ => usage(),
+        }
+    }
+
+    let result = source
+        .open(Day1::DAY)
+        .map_err(|e| e.to_string())
+        .and_then(|reader| solve_external(reader, run_size).map_err(|e| e.to_string()));
+
+    match result {
+        Ok((distance, similarity)) => println!("{}\n{}", distance, similarity),
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(1);
+        }
+    }
 }
@@ -0,0 +1,38 @@
+use std::fs;
+
+use aoc_core::Answer;
+
+#[test]
+fn external_matches_in_memory() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+    for file in [
+        "sample.txt",
+        "duplicates.txt",
+        "signed.txt",
+        "unsigned.txt",
+        "../../inputs/day-1.txt",
+    ] {
+        let input = fs::read_to_string(format!("{}/{}", dir, file)).unwrap();
+        let lists = day_1::parse(&input).unwrap();
+
+        // small runs force the runs to be compacted before they are merged
+        for run_size in [1, 3, 1000] {
+            let (distance, similarity) = day_1::solve_external(input.as_bytes(), run_size).unwrap();
+
+            assert_eq!(Answer::from(distance), day_1::part1(&lists), "{}", file);
+            assert_eq!(Answer::from(similarity), day_1::part2(&lists), "{}", file);
+        }
+    }
+}
+
+#[test]
+fn rejects_ids_beyond_64_bits() {
+    let e = day_1::parse("1 2\n18446744073709551616 1").unwrap_err();
+
+    assert_eq!((e.line(), e.column()), (2, 1));
+    assert!(e.expected().contains("fits in 64 bits"), "{}", e);
+
+    assert!(day_1::parse("-9223372036854775808 18446744073709551615").is_ok());
+    assert!(day_1::parse("-9223372036854775809 1").is_err());
+}
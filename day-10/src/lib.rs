@@ -0,0 +1,66 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{Answer, ParseError, Result, Solution};
+use aoc_grid::{Grid, Point};
+
+pub type Input = Grid<u8>;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    Grid::parse(input, |c| c.to_digit(10).map_or(10, |d| d as u8))
+        .map_err(|e| ParseError::from_grid(input, e))
+}
+
+pub fn part1(grid: &Input) -> Answer {
+    let score_sum = find_trailheads(grid)
+        .values()
+        .fold(0, |sum, v| sum + v.iter().collect::<HashSet<_>>().len());
+
+    score_sum.into()
+}
+
+pub fn part2(grid: &Input) -> Answer {
+    let rating_sum = find_trailheads(grid)
+        .values()
+        .fold(0, |sum, v| sum + v.len());
+
+    rating_sum.into()
+}
+
+fn find_trailheads(grid: &Grid<u8>) -> HashMap<Point, Vec<Point>> {
+    grid.iter()
+        .filter(|&(_, &elevation)| elevation == 0)
+        .map(|(p, _)| (p, find_ends(grid, p, 0)))
+        .collect()
+}
+
+fn find_ends(grid: &Grid<u8>, position: Point, elevation: u8) -> Vec<Point> {
+    if grid[position] != elevation {
+        vec![]
+    } else if elevation == 9 {
+        vec![position]
+    } else {
+        grid.neighbors4(position)
+            .flat_map(|neighbor| find_ends(grid, neighbor, elevation + 1))
+            .collect()
+    }
+}
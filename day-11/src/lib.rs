@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use aoc_core::{parse_all, Answer, IResult, Result, Solution};
+use nom::{
+    character::complete::{space1, u64},
+    multi::separated_list1,
+};
+
+pub type Input = HashMap<u64, u64>;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    parse_all(input, parse_input)
+}
+
+pub fn part1(stone_counts: &Input) -> Answer {
+    let stone_counts = (0..25).fold(stone_counts.clone(), |acc, _| blink(acc));
+    stone_counts.values().sum::<u64>().into()
+}
+
+pub fn part2(stone_counts: &Input) -> Answer {
+    let stone_counts = (0..75).fold(stone_counts.clone(), |acc, _| blink(acc));
+    stone_counts.values().sum::<u64>().into()
+}
+
+fn parse_input(i: &str) -> IResult<'_, HashMap<u64, u64>> {
+    separated_list1(space1, u64)(i).map(|(i, v)| {
+        (
+            i,
+            v.into_iter().fold(HashMap::new(), |mut acc, n| {
+                *acc.entry(n).or_default() += 1;
+                acc
+            }),
+        )
+    })
+}
+
+fn blink(stones: HashMap<u64, u64>) -> HashMap<u64, u64> {
+    stones
+        .into_iter()
+        .fold(HashMap::new(), |mut acc, (n, count)| {
+            if n == 0 {
+                *acc.entry(1).or_default() += count;
+            } else {
+                let digits = n.checked_ilog10().unwrap_or(0) + 1;
+
+                if digits % 2 == 0 {
+                    let split = 10_u64.pow(digits / 2);
+                    *acc.entry(n / split).or_default() += count;
+                    *acc.entry(n % split).or_default() += count;
+                } else {
+                    *acc.entry(n * 2024).or_default() += count;
+                }
+            }
+
+            acc
+        })
+}
@@ -0,0 +1,130 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    iter::zip,
+};
+
+use aoc_core::{Answer, ParseError, Result, Solution};
+use aoc_grid::{Grid, Point, DIRECTIONS_4};
+
+struct Region {
+    plant: char,
+    points: HashSet<Point>,
+}
+
+pub type Input = Grid<char>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    Grid::parse(input, |c| c).map_err(|e| ParseError::from_grid(input, e))
+}
+
+pub fn part1(grid: &Input) -> Answer {
+    let regions = find_regions(grid);
+    let perimeters_cost = regions.iter().fold(0, |c, r| c + (area(r) * perimeter(r)));
+
+    perimeters_cost.into()
+}
+
+pub fn part2(grid: &Input) -> Answer {
+    let regions = find_regions(grid);
+    let sides_cost = regions.iter().fold(0, |c, r| c + (area(r) * sides(r)));
+
+    sides_cost.into()
+}
+
+impl Region {
+    fn translate(&self, &Point(x, y): &Point, &(dx, dy): &(isize, isize)) -> Option<Point> {
+        let (xn, xo) = x.overflowing_add_signed(dx);
+        let (yn, yo) = y.overflowing_add_signed(dy);
+
+        if xo || yo {
+            return None;
+        }
+
+        let point = Point(xn, yn);
+
+        if self.points.contains(&point) {
+            Some(point)
+        } else {
+            None
+        }
+    }
+}
+
+fn find_regions(grid: &Grid<char>) -> Vec<Region> {
+    let mut regions: Vec<Region> = Vec::new();
+
+    for point in grid.points() {
+        if regions.iter().any(|r| r.points.contains(&point)) {
+            continue;
+        }
+
+        let mut region = Region {
+            plant: grid[point],
+            points: HashSet::new(),
+        };
+
+        let mut neighbors = VecDeque::from([point]);
+
+        while let Some(position) = neighbors.pop_front() {
+            if grid[position] != region.plant || region.points.contains(&position) {
+                continue;
+            }
+
+            neighbors.extend(grid.neighbors4(position));
+            region.points.insert(position);
+        }
+
+        regions.push(region);
+    }
+
+    regions
+}
+
+fn area(region: &Region) -> usize {
+    region.points.len()
+}
+
+fn perimeter(region: &Region) -> usize {
+    region.points.iter().fold(0, |sum, point| {
+        sum + DIRECTIONS_4
+            .iter()
+            .filter(|&delta| region.translate(point, delta).is_none())
+            .count()
+    })
+}
+
+fn sides(region: &Region) -> usize {
+    region.points.iter().fold(0, |sum, point| {
+        sum + zip(DIRECTIONS_4.iter(), DIRECTIONS_4.iter().cycle().skip(1))
+            .filter(|&(d1, d2)| {
+                let n1 = region.translate(point, d1);
+                let n2 = region.translate(point, d2);
+                let n3 = region.translate(point, &(d1.0 + d2.0, d1.1 + d2.1));
+
+                let exterior = n1.is_none() && n2.is_none();
+                let interior = n1.is_some() && n2.is_some() && n3.is_none();
+                exterior || interior
+            })
+            .count()
+    })
+}
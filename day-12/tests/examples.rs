@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_core::check_examples::<day_12::Day12>(env!("CARGO_MANIFEST_DIR"));
+}
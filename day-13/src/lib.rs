@@ -0,0 +1,116 @@
+use std::ops::AddAssign;
+
+use aoc_core::{parse_all, Answer, IResult, Result, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, i64, newline},
+    combinator::cut,
+    error::context,
+    multi::{many1, separated_list0},
+    sequence::tuple,
+};
+
+#[derive(Clone)]
+pub struct Machine {
+    a: Point,
+    b: Point,
+    prize: Point,
+}
+
+#[derive(Clone)]
+struct Point(i64, i64);
+
+impl AddAssign<i64> for Point {
+    fn add_assign(&mut self, rhs: i64) {
+        self.0 += rhs;
+        self.1 += rhs;
+    }
+}
+
+pub type Input = Vec<Machine>;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    parse_all(input, parse_input)
+}
+
+pub fn part1(machines: &Input) -> Answer {
+    let total_cost = machines.iter().filter_map(solve).sum::<i64>();
+    total_cost.into()
+}
+
+pub fn part2(machines: &Input) -> Answer {
+    let mut machines = machines.clone();
+
+    for machine in machines.iter_mut() {
+        machine.prize += 10000000000000;
+    }
+
+    let total_cost_ridiculous = machines.iter().filter_map(solve).sum::<i64>();
+    total_cost_ridiculous.into()
+}
+
+fn parse_button(c: char) -> impl Fn(&str) -> IResult<'_, Point> {
+    move |i| {
+        let (i, _) = tuple((tag("Button "), char(c)))(i)?;
+
+        context("button", cut(tuple((tag(": X"), i64, tag(", Y"), i64))))(i)
+            .map(|(i, (_, x, _, y))| (i, Point(x, y)))
+    }
+}
+
+fn parse_prize(i: &str) -> IResult<'_, Point> {
+    context("prize", tuple((tag("Prize: X="), i64, tag(", Y="), i64)))(i)
+        .map(|(i, (_, x, _, y))| (i, Point(x, y)))
+}
+
+fn parse_machine(i: &str) -> IResult<'_, Machine> {
+    let (i, a) = parse_button('A')(i)?;
+    let (i, (_, b, _, prize)) = cut(tuple((newline, parse_button('B'), newline, parse_prize)))(i)?;
+
+    Ok((i, Machine { a, b, prize }))
+}
+
+fn parse_input(i: &str) -> IResult<'_, Vec<Machine>> {
+    separated_list0(many1(newline), parse_machine)(i)
+}
+
+fn solve(
+    &Machine {
+        a: Point(ax, ay),
+        b: Point(bx, by),
+        prize: Point(px, py),
+    }: &Machine,
+) -> Option<i64> {
+    let d = (ax * by) - (ay * bx);
+    let a = ((px * by) - (py * bx)) / d;
+    let b = ((ax * py) - (ay * px)) / d;
+
+    let x = (a * ax) + (b * bx);
+    let y = (a * ay) + (b * by);
+
+    if x == px && y == py {
+        Some((a * 3) + b)
+    } else {
+        None
+    }
+}
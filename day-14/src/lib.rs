@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use aoc_core::{parse_lines, Answer, IResult, Result, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{i64, space1, u64},
+    error::context,
+    sequence::tuple,
+};
+
+#[derive(Eq, Hash, PartialEq)]
+struct Point(u64, u64);
+
+struct Velocity(i64, i64);
+
+pub struct Robot {
+    position: Point,
+    velocity: Velocity,
+}
+
+const WIDTH: u64 = 101;
+const HEIGHT: u64 = 103;
+
+pub type Input = Vec<Robot>;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    parse_lines(input, parse_line)
+}
+
+pub fn part1(robots: &Input) -> Answer {
+    let positions = robots.iter().map(|r| position_at(r, 100, WIDTH, HEIGHT));
+    let positions_by_quadrant = into_quadrants(positions, WIDTH, HEIGHT);
+    let safety_factor = positions_by_quadrant.iter().fold(1, |acc, q| acc * q.len());
+
+    safety_factor.into()
+}
+
+pub fn part2(robots: &Input) -> Answer {
+    let tree_time = find_tree_time(robots, WIDTH, HEIGHT).expect("nope");
+    tree_time.into()
+}
+
+fn parse_position(i: &str) -> IResult<'_, Point> {
+    context("position", tuple((tag("p="), u64, tag(","), u64)))(i)
+        .map(|(i, (_, x, _, y))| (i, Point(x, y)))
+}
+
+fn parse_velocity(i: &str) -> IResult<'_, Velocity> {
+    context("velocity", tuple((tag("v="), i64, tag(","), i64)))(i)
+        .map(|(i, (_, x, _, y))| (i, Velocity(x, y)))
+}
+
+fn parse_line(i: &str) -> IResult<'_, Robot> {
+    tuple((parse_position, space1, parse_velocity))(i)
+        .map(|(i, (position, _, velocity))| (i, Robot { position, velocity }))
+}
+
+fn position_at(
+    &Robot {
+        position: Point(x, y),
+        velocity: Velocity(vx, vy),
+    }: &Robot,
+    t: i64,
+    width: u64,
+    height: u64,
+) -> Point {
+    let mut x = (x as i64 + (vx * t)) % width as i64;
+    let mut y = (y as i64 + (vy * t)) % height as i64;
+
+    if x < 0 {
+        x += width as i64;
+    }
+
+    if y < 0 {
+        y += height as i64;
+    }
+
+    Point(x as u64, y as u64)
+}
+
+fn into_quadrants(points: impl Iterator<Item = Point>, width: u64, height: u64) -> [Vec<Point>; 4] {
+    let mut quadrants = [const { vec![] }; 4];
+
+    for point in points {
+        match point {
+            Point(x, y) if x < width / 2 && y < height / 2 => quadrants[0].push(point),
+            Point(x, y) if x > width / 2 && y < height / 2 => quadrants[1].push(point),
+            Point(x, y) if x < width / 2 && y > height / 2 => quadrants[2].push(point),
+            Point(x, y) if x > width / 2 && y > height / 2 => quadrants[3].push(point),
+            _ => (),
+        }
+    }
+
+    quadrants
+}
+
+fn find_tree_time(robots: &[Robot], width: u64, height: u64) -> Option<i64> {
+    (1..).find(|&t| {
+        robots
+            .iter()
+            .map(|r| position_at(r, t, width, height))
+            .collect::<HashSet<_>>()
+            .len()
+            == robots.len()
+    })
+}
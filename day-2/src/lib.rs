@@ -0,0 +1,290 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Write};
+
+use aoc_core::{parse_lines, Answer, IResult, Result, Solution};
+use nom::{
+    character::complete::{i64, space1},
+    multi::separated_list0,
+};
+
+pub type Input = Vec<Vec<i64>>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    /// Either direction, as long as the whole report keeps to it.
+    Either,
+}
+
+/// What makes a report safe: every step between consecutive levels moves in the allowed
+/// direction by `min_step..=max_step`, once up to `tolerance` levels are removed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SafetyPolicy {
+    pub min_step: u64,
+    pub max_step: u64,
+    pub direction: Direction,
+    pub tolerance: usize,
+}
+
+/// Why a pair of consecutive levels breaks a policy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Violation {
+    DirectionChange,
+    ZeroStep,
+    StepTooSmall,
+    StepTooBig,
+}
+
+pub struct Diagnosis {
+    /// The first pair of levels that breaks the policy, by the index of the first of the two.
+    pub violation: Option<(usize, Violation)>,
+    /// Indices of the levels to remove to make the report safe, if within tolerance.
+    pub removals: Option<Vec<usize>>,
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    parse_lines(input, parse_line)
+}
+
+pub fn part1(reports: &Input) -> Answer {
+    count_safe(reports, &SafetyPolicy::default()).into()
+}
+
+pub fn part2(reports: &Input) -> Answer {
+    let dampened = SafetyPolicy {
+        tolerance: 1,
+        ..SafetyPolicy::default()
+    };
+
+    count_safe(reports, &dampened).into()
+}
+
+pub fn count_safe(reports: &Input, policy: &SafetyPolicy) -> usize {
+    reports.iter().filter(|r| policy.is_safe(r)).count()
+}
+
+/// Describes each report under the puzzle's rules with the dampener: where it first breaks
+/// them and why, and which level to remove to make it safe.
+pub fn diagnose(reports: &Input) -> String {
+    let policy = SafetyPolicy {
+        tolerance: 1,
+        ..SafetyPolicy::default()
+    };
+
+    let mut output = String::new();
+
+    for (line, report) in reports.iter().enumerate() {
+        write!(output, "line {}: ", line + 1).unwrap();
+
+        let Diagnosis {
+            violation,
+            removals,
+        } = policy.diagnose(report);
+
+        let Some((i, violation)) = violation else {
+            writeln!(output, "safe").unwrap();
+            continue;
+        };
+
+        write!(
+            output,
+            "unsafe at index {} ({} -> {}): {}; ",
+            i,
+            report[i],
+            report[i + 1],
+            violation
+        )
+        .unwrap();
+
+        match removals.as_deref() {
+            Some(&[removed]) => writeln!(
+                output,
+                "safe after removing index {} ({})",
+                removed, report[removed]
+            ),
+            _ => writeln!(output, "unsafe even after removing one level"),
+        }
+        .unwrap();
+    }
+
+    output
+}
+
+impl Default for SafetyPolicy {
+    /// The puzzle's rules: steps of 1 to 3 in either direction, with nothing removed.
+    fn default() -> Self {
+        SafetyPolicy {
+            min_step: 1,
+            max_step: 3,
+            direction: Direction::Either,
+            tolerance: 0,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    pub fn is_safe(&self, report: &[i64]) -> bool {
+        self.min_removals(report).is_some()
+    }
+
+    /// The fewest levels that must be removed for the report to be safe, or `None` if that is
+    /// more than the tolerance.
+    pub fn min_removals(&self, report: &[i64]) -> Option<usize> {
+        self.removals(report).map(|removed| removed.len())
+    }
+
+    /// Indices of the fewest levels whose removal makes the report safe, or `None` if that is
+    /// more than the tolerance.
+    pub fn removals(&self, report: &[i64]) -> Option<Vec<usize>> {
+        match self.direction {
+            Direction::Either => [Direction::Increasing, Direction::Decreasing]
+                .into_iter()
+                .filter_map(|direction| self.removals_in(report, direction))
+                .min_by_key(Vec::len),
+            direction => self.removals_in(report, direction),
+        }
+    }
+
+    /// The first pair of consecutive levels that breaks the policy, by the index of the first
+    /// of the two, regardless of tolerance. Without a fixed direction, the report's direction
+    /// is set by its first step.
+    pub fn first_violation(&self, report: &[i64]) -> Option<(usize, Violation)> {
+        let mut direction = self.direction;
+
+        for (i, window) in report.windows(2).enumerate() {
+            let (a, b) = (window[0], window[1]);
+            let step = a.abs_diff(b);
+
+            let going = match b.cmp(&a) {
+                Ordering::Greater => Direction::Increasing,
+                Ordering::Less => Direction::Decreasing,
+                Ordering::Equal if self.min_step > 0 => return Some((i, Violation::ZeroStep)),
+                Ordering::Equal => direction,
+            };
+
+            if direction == Direction::Either {
+                direction = going;
+            } else if going != direction {
+                return Some((i, Violation::DirectionChange));
+            }
+
+            if step < self.min_step {
+                return Some((i, Violation::StepTooSmall));
+            }
+
+            if step > self.max_step {
+                return Some((i, Violation::StepTooBig));
+            }
+        }
+
+        None
+    }
+
+    pub fn diagnose(&self, report: &[i64]) -> Diagnosis {
+        Diagnosis {
+            violation: self.first_violation(report),
+            removals: self.removals(report),
+        }
+    }
+
+    /// Whether `a` can be followed directly by `b` when moving in `direction`.
+    pub fn allows_step(&self, a: i64, b: i64, direction: Direction) -> bool {
+        let step = match direction {
+            Direction::Increasing => b as i128 - a as i128,
+            Direction::Decreasing => a as i128 - b as i128,
+            Direction::Either => (b as i128 - a as i128).abs(),
+        };
+
+        (self.min_step as i128..=self.max_step as i128).contains(&step)
+    }
+
+    /// Finds, for each level, the fewest removals that leave it as the last level kept so far,
+    /// and the level kept before it. Removing more than the tolerance is never useful, so only
+    /// the `tolerance + 1` levels before it can precede it, which keeps this linear in the length
+    /// of the report.
+    fn removals_in(&self, report: &[i64], direction: Direction) -> Option<Vec<usize>> {
+        // removing more levels than there are is no different from removing all of them
+        let k = self.tolerance.min(report.len());
+        let mut best: Vec<Option<(usize, Option<usize>)>> = Vec::with_capacity(report.len());
+
+        for (i, &level) in report.iter().enumerate() {
+            // every level before this one removed
+            let mut candidate = (i <= k).then_some((i, None));
+
+            for j in (i.saturating_sub(k + 1)..i).rev() {
+                let Some((before, _)) = best[j] else {
+                    continue;
+                };
+
+                let total = before + (i - j - 1);
+
+                if total <= k
+                    && candidate.is_none_or(|(c, _)| total < c)
+                    && self.allows_step(report[j], level, direction)
+                {
+                    candidate = Some((total, Some(j)));
+                }
+            }
+
+            best.push(candidate);
+        }
+
+        let last = best
+            .iter()
+            .enumerate()
+            .filter_map(|(i, b)| b.map(|(total, _)| (total + (report.len() - 1 - i), i)))
+            .filter(|&(total, _)| total <= k)
+            .min()
+            .map(|(_, i)| i);
+
+        let mut kept = vec![false; report.len()];
+        let mut next = match last {
+            Some(last) => Some(last),
+            None if report.is_empty() => None,
+            None => return None,
+        };
+
+        while let Some(i) = next {
+            kept[i] = true;
+            next = best[i].and_then(|(_, parent)| parent);
+        }
+
+        Some((0..report.len()).filter(|&i| !kept[i]).collect())
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::DirectionChange => write!(f, "direction change"),
+            Violation::ZeroStep => write!(f, "zero step"),
+            Violation::StepTooSmall => write!(f, "step too small"),
+            Violation::StepTooBig => write!(f, "step too big"),
+        }
+    }
+}
+
+fn parse_line(i: &str) -> IResult<'_, Vec<i64>> {
+    separated_list0(space1, i64)(i)
+}
@@ -0,0 +1,141 @@
+use day_2::{Direction, SafetyPolicy, Violation};
+
+/// Checks every way of removing up to `tolerance` levels.
+fn brute_force(policy: &SafetyPolicy, report: &[i64]) -> Option<usize> {
+    (0..1_u32 << report.len())
+        .filter(|removed| removed.count_ones() as usize <= policy.tolerance)
+        .filter(|removed| {
+            let kept: Vec<_> = (0..report.len())
+                .filter(|i| removed & (1 << i) == 0)
+                .map(|i| report[i])
+                .collect();
+
+            [Direction::Increasing, Direction::Decreasing]
+                .into_iter()
+                .filter(|&d| policy.direction == Direction::Either || policy.direction == d)
+                .any(|d| kept.windows(2).all(|w| policy.allows_step(w[0], w[1], d)))
+        })
+        .map(|removed| removed.count_ones() as usize)
+        .min()
+}
+
+#[test]
+fn removals_match_brute_force() {
+    let mut seed = 0x2024_u64;
+    let mut random = |n: u64| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) % n
+    };
+
+    for _ in 0..2000 {
+        let length = random(9) as usize;
+        let report: Vec<i64> = (0..length).map(|_| random(12) as i64 - 6).collect();
+        let min_step = random(3);
+        let policy = SafetyPolicy {
+            min_step,
+            max_step: min_step + random(4),
+            direction: [
+                Direction::Increasing,
+                Direction::Decreasing,
+                Direction::Either,
+            ][random(3) as usize],
+            tolerance: random(4) as usize,
+        };
+
+        assert_eq!(
+            policy.min_removals(&report),
+            brute_force(&policy, &report),
+            "{:?} {:?}",
+            policy,
+            report
+        );
+
+        let strict = SafetyPolicy {
+            tolerance: 0,
+            ..policy
+        };
+
+        assert_eq!(
+            strict.first_violation(&report).is_none(),
+            strict.is_safe(&report),
+            "{:?} {:?}",
+            policy,
+            report
+        );
+
+        if let Some(removals) = policy.removals(&report) {
+            let kept: Vec<_> = (0..report.len())
+                .filter(|i| !removals.contains(i))
+                .map(|i| report[i])
+                .collect();
+
+            assert!(strict.is_safe(&kept), "{:?} {:?}", policy, report);
+        }
+    }
+}
+
+#[test]
+fn unlimited_tolerance_removes_all_but_one_level() {
+    let policy = SafetyPolicy {
+        tolerance: usize::MAX,
+        ..SafetyPolicy::default()
+    };
+
+    assert_eq!(policy.removals(&[5, 5, 5, 5]), Some(vec![1, 2, 3]));
+    assert_eq!(policy.removals(&[1, 2, 9, 3]), Some(vec![2]));
+    assert_eq!(policy.removals(&[]), Some(vec![]));
+}
+
+#[test]
+fn diagnoses_the_first_violation_and_the_level_to_remove() {
+    let policy = SafetyPolicy {
+        tolerance: 1,
+        ..SafetyPolicy::default()
+    };
+    let diagnose = |report: &[i64]| {
+        let diagnosis = policy.diagnose(report);
+        (diagnosis.violation, diagnosis.removals)
+    };
+
+    // violations are reported at the first level of the pair that breaks the rules
+    assert_eq!(
+        diagnose(&[9, 1, 2, 3, 4]),
+        (Some((0, Violation::StepTooBig)), Some(vec![0]))
+    );
+    assert_eq!(diagnose(&[3, 3, 3]), (Some((0, Violation::ZeroStep)), None));
+    assert_eq!(
+        diagnose(&[1, 3, 2, 4, 5]),
+        (Some((1, Violation::DirectionChange)), Some(vec![1]))
+    );
+    assert_eq!(
+        diagnose(&[1, 2, 7, 8, 9]),
+        (Some((1, Violation::StepTooBig)), None)
+    );
+    assert_eq!(diagnose(&[7, 6, 4, 2, 1]), (None, Some(vec![])));
+
+    let wide = SafetyPolicy {
+        min_step: 2,
+        ..policy
+    };
+    assert_eq!(
+        wide.diagnose(&[1, 3, 4, 6]).violation,
+        Some((1, Violation::StepTooSmall))
+    );
+}
+
+#[test]
+fn diagnosis_report() {
+    let reports = day_2::parse(include_str!("../examples/sample.txt")).unwrap();
+
+    assert_eq!(
+        day_2::diagnose(&reports),
+        "line 1: safe\n\
+         line 2: unsafe at index 1 (2 -> 7): step too big; unsafe even after removing one level\n\
+         line 3: unsafe at index 2 (6 -> 2): step too big; unsafe even after removing one level\n\
+         line 4: unsafe at index 1 (3 -> 2): direction change; safe after removing index 1 (3)\n\
+         line 5: unsafe at index 2 (4 -> 4): zero step; safe after removing index 2 (4)\n\
+         line 6: safe\n"
+    );
+}
@@ -0,0 +1,203 @@
+use std::ops::Range;
+
+use crate::{run, Instruction, Program, Status, Token, PUZZLE};
+
+/// How a fragment of the corrupted memory is highlighted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mark {
+    /// An instruction that was executed.
+    Enabled,
+    /// An instruction that was skipped because instructions were disabled.
+    Disabled,
+    /// An instruction that enables or disables others.
+    Control,
+    /// An instruction that could not be executed.
+    Failed,
+    /// Something that looks like an instruction but is not one, such as `mul(4*` or
+    /// `mul ( 2 , 4 )`.
+    NearMiss,
+}
+
+/// Longest fragment after an instruction's name considered part of a near miss.
+const NEAR_MISS_LENGTH: usize = 16;
+
+/// Finds fragments that start with an instruction's name followed by something bracket-like,
+/// but did not scan as an instruction.
+pub fn near_misses(
+    source: &str,
+    tokens: &[Token],
+    instructions: &[Instruction],
+) -> Vec<Range<usize>> {
+    let bytes = source.as_bytes();
+    let mut tokens = tokens.iter().peekable();
+    let mut misses = vec![];
+    let mut position = 0;
+
+    while position < bytes.len() {
+        while tokens.next_if(|t| t.span.end <= position).is_some() {}
+
+        if let Some(token) = tokens.peek().filter(|t| t.span.start <= position) {
+            position = token.span.end;
+            continue;
+        }
+
+        // a near miss never runs into the next instruction
+        let end = tokens.peek().map_or(bytes.len(), |t| t.span.start);
+
+        let miss = instructions
+            .iter()
+            .filter_map(|instruction| near_miss_length(&bytes[position..end], instruction.name))
+            .max();
+
+        match miss {
+            Some(length) => {
+                misses.push(position..position + length);
+                position += length;
+            }
+            None => position += 1,
+        }
+    }
+
+    misses
+}
+
+fn near_miss_length(i: &[u8], name: &str) -> Option<usize> {
+    if !i.starts_with(name.as_bytes()) {
+        return None;
+    }
+
+    let rest = &i[name.len()..];
+    let opening = rest
+        .iter()
+        .take(NEAR_MISS_LENGTH)
+        .position(|&b| b != b' ' && b != b'\t')?;
+
+    if !matches!(rest[opening], b'(' | b'[' | b'{' | b'<') {
+        return None;
+    }
+
+    let mut length = opening + 1;
+
+    while length < rest.len().min(NEAR_MISS_LENGTH) {
+        match rest[length] {
+            b')' => return Some(name.len() + length + 1),
+            b'0'..=b'9' | b',' | b' ' | b'\t' => length += 1,
+            // the character that broke the instruction
+            b if b.is_ascii_graphic() => return Some(name.len() + length + 1),
+            _ => break,
+        }
+    }
+
+    Some(name.len() + length)
+}
+
+/// Every highlighted fragment of the program, in order.
+pub fn marks(program: &Program) -> Vec<(Range<usize>, Mark)> {
+    let run = run(&program.tokens);
+
+    let mut marks: Vec<_> = program
+        .tokens
+        .iter()
+        .zip(run.statuses)
+        .map(|(token, status)| {
+            let mark = match status {
+                _ if token.instruction.control => Mark::Control,
+                Status::Executed => Mark::Enabled,
+                Status::Skipped => Mark::Disabled,
+                Status::Failed(_) => Mark::Failed,
+            };
+
+            (token.span.clone(), mark)
+        })
+        .chain(
+            near_misses(&program.source, &program.tokens, PUZZLE)
+                .into_iter()
+                .map(|span| (span, Mark::NearMiss)),
+        )
+        .collect();
+
+    marks.sort_by_key(|(span, _)| span.start);
+    marks
+}
+
+/// Re-prints the program with ANSI colours, dimming everything that is not highlighted.
+pub fn annotate_ansi(program: &Program) -> String {
+    render(program, |text, mark| {
+        let style = match mark {
+            None => "2",
+            Some(Mark::Enabled) => "1;32",
+            Some(Mark::Disabled) => "31",
+            Some(Mark::Control) => "1;36",
+            Some(Mark::Failed) => "1;35",
+            Some(Mark::NearMiss) => "4;33",
+        };
+
+        format!("\x1b[{}m{}\x1b[0m", style, text)
+    })
+}
+
+/// Re-prints the program as an HTML page.
+pub fn annotate_html(program: &Program) -> String {
+    let body = render(program, |text, mark| {
+        let text = text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+
+        let class = match mark {
+            None => return text,
+            Some(Mark::Enabled) => "enabled",
+            Some(Mark::Disabled) => "disabled",
+            Some(Mark::Control) => "control",
+            Some(Mark::Failed) => "failed",
+            Some(Mark::NearMiss) => "near-miss",
+        };
+
+        format!("<span class=\"{}\">{}</span>", class, text)
+    });
+
+    format!(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<style>
+pre {{ color: #999; white-space: pre-wrap; word-break: break-all; }}
+.enabled {{ color: #080; font-weight: bold; }}
+.disabled {{ color: #c00; }}
+.control {{ color: #08c; font-weight: bold; }}
+.failed {{ color: #c0c; font-weight: bold; }}
+.near-miss {{ color: #b80; text-decoration: underline; }}
+</style>
+</head>
+<body>
+<pre>{}</pre>
+</body>
+</html>
+",
+        body
+    )
+}
+
+fn render(program: &Program, style: impl Fn(&str, Option<Mark>) -> String) -> String {
+    let source = &program.source;
+    let mut output = String::with_capacity(source.len() * 2);
+    let mut position = 0;
+
+    for (span, mark) in marks(program) {
+        if position < span.start {
+            output += &style(&source[position..span.start], None);
+        }
+
+        output += &style(&source[span.clone()], Some(mark));
+        position = span.end;
+    }
+
+    if position < source.len() {
+        output += &style(&source[position..], None);
+    }
+
+    output.push('\n');
+
+    output
+}
@@ -0,0 +1,159 @@
+use std::fmt::{self, Display};
+
+use crate::Token;
+
+/// Defines an instruction: what it is called, how many operands it takes and what it does.
+pub struct Instruction {
+    pub name: &'static str,
+    pub arity: usize,
+    /// Whether it runs even while instructions are disabled, as do() and don't() do.
+    pub control: bool,
+    pub execute: fn(&mut State, &[u32]) -> Result<(), String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct State {
+    pub enabled: bool,
+    pub total: i64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    Executed,
+    /// Not run because instructions were disabled.
+    Skipped,
+    Failed(String),
+}
+
+/// The outcome of running a program, with the status of each of its instructions in order.
+pub struct Run {
+    pub state: State,
+    pub statuses: Vec<Status>,
+}
+
+pub const DO: Instruction = Instruction {
+    name: "do",
+    arity: 0,
+    control: true,
+    execute: |state, _| {
+        state.enabled = true;
+        Ok(())
+    },
+};
+
+pub const DONT: Instruction = Instruction {
+    name: "don't",
+    arity: 0,
+    control: true,
+    execute: |state, _| {
+        state.enabled = false;
+        Ok(())
+    },
+};
+
+/// Flips between enabled and disabled.
+pub const TOGGLE: Instruction = Instruction {
+    name: "toggle",
+    arity: 0,
+    control: true,
+    execute: |state, _| {
+        state.enabled = !state.enabled;
+        Ok(())
+    },
+};
+
+/// Enables instructions if its operand is non-zero, and disables them otherwise.
+pub const IF: Instruction = Instruction {
+    name: "if",
+    arity: 1,
+    control: true,
+    execute: |state, args| {
+        state.enabled = args[0] != 0;
+        Ok(())
+    },
+};
+
+pub const MUL: Instruction = Instruction {
+    name: "mul",
+    arity: 2,
+    control: false,
+    execute: |state, args| {
+        state.total += args[0] as i64 * args[1] as i64;
+        Ok(())
+    },
+};
+
+pub const ADD: Instruction = Instruction {
+    name: "add",
+    arity: 2,
+    control: false,
+    execute: |state, args| {
+        state.total += args[0] as i64 + args[1] as i64;
+        Ok(())
+    },
+};
+
+pub const SUB: Instruction = Instruction {
+    name: "sub",
+    arity: 2,
+    control: false,
+    execute: |state, args| {
+        state.total += args[0] as i64 - args[1] as i64;
+        Ok(())
+    },
+};
+
+/// Adds the quotient, rounded toward zero.
+pub const DIV: Instruction = Instruction {
+    name: "div",
+    arity: 2,
+    control: false,
+    execute: |state, args| match args[1] {
+        0 => Err("division by zero".to_owned()),
+        divisor => {
+            state.total += (args[0] / divisor) as i64;
+            Ok(())
+        }
+    },
+};
+
+/// The instructions in the puzzle.
+pub const PUZZLE: &[Instruction] = &[DO, DONT, MUL];
+
+pub const EXTENDED: &[Instruction] = &[DO, DONT, TOGGLE, IF, MUL, ADD, SUB, DIV];
+
+/// Runs instructions in order, starting enabled with a total of zero.
+pub fn run<'t>(tokens: impl IntoIterator<Item = &'t Token<'t>>) -> Run {
+    let mut state = State {
+        enabled: true,
+        total: 0,
+    };
+
+    let statuses = tokens
+        .into_iter()
+        .map(|token| {
+            let instruction = token.instruction;
+
+            if !state.enabled && !instruction.control {
+                Status::Skipped
+            } else {
+                match (instruction.execute)(&mut state, &token.args) {
+                    Ok(()) => Status::Executed,
+                    Err(e) => Status::Failed(e),
+                }
+            }
+        })
+        .collect();
+
+    Run { state, statuses }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Executed => write!(f, "executed"),
+            Status::Skipped => write!(f, "skipped"),
+            Status::Failed(e) => write!(f, "failed: {}", e),
+        }
+    }
+}
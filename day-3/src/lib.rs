@@ -0,0 +1,209 @@
+use std::fmt::{self, Display, Write};
+use std::ops::Range;
+
+use aoc_core::{Answer, Result, Solution};
+
+mod annotate;
+pub mod interpreter;
+
+pub use annotate::{annotate_ansi, annotate_html, marks, near_misses, Mark};
+pub use interpreter::{run, Instruction, Run, State, Status, EXTENDED, PUZZLE};
+
+/// An instruction found in the corrupted memory, with its operands and the byte range it was
+/// read from.
+#[derive(Clone)]
+pub struct Token<'t> {
+    pub instruction: &'t Instruction,
+    pub args: Vec<u32>,
+    pub span: Range<usize>,
+}
+
+/// Finds the instructions of a table in a single pass, skipping everything else.
+pub struct Scanner<'a, 't> {
+    input: &'a [u8],
+    instructions: &'t [Instruction],
+    position: usize,
+}
+
+/// The instructions found in the corrupted memory, kept with the memory itself.
+pub struct Program {
+    pub source: String,
+    pub tokens: Vec<Token<'static>>,
+}
+
+pub type Input = Program;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    Ok(Program {
+        source: input.to_owned(),
+        tokens: Scanner::new(input, PUZZLE).collect(),
+    })
+}
+
+pub fn part1(program: &Input) -> Answer {
+    let sum_muls_only = run(program.tokens.iter().filter(|t| !t.instruction.control))
+        .state
+        .total;
+
+    sum_muls_only.into()
+}
+
+pub fn part2(program: &Input) -> Answer {
+    run(&program.tokens).state.total.into()
+}
+
+/// Lists every instruction with where it was found and whether it was executed.
+pub fn trace(program: &Input) -> String {
+    let tokens = &program.tokens;
+    let run = run(tokens);
+    let mut output = String::new();
+
+    for (token, status) in tokens.iter().zip(&run.statuses) {
+        writeln!(
+            output,
+            "{:<16}  {:<16}  {}",
+            format!("{}..{}", token.span.start, token.span.end),
+            token.to_string(),
+            status
+        )
+        .unwrap();
+    }
+
+    let skipped = run
+        .statuses
+        .iter()
+        .filter(|&s| s == &Status::Skipped)
+        .count();
+    let failed = run
+        .statuses
+        .iter()
+        .filter(|s| matches!(s, Status::Failed(_)))
+        .count();
+
+    writeln!(
+        output,
+        "{} executed, {} skipped, {} failed, total {}",
+        run.statuses.len() - skipped - failed,
+        skipped,
+        failed,
+        run.state.total
+    )
+    .unwrap();
+
+    output
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args: Vec<_> = self.args.iter().map(u32::to_string).collect();
+        write!(f, "{}({})", self.instruction.name, args.join(","))
+    }
+}
+
+impl<'a, 't> Scanner<'a, 't> {
+    pub fn new(input: &'a str, instructions: &'t [Instruction]) -> Self {
+        Scanner {
+            input: input.as_bytes(),
+            instructions,
+            position: 0,
+        }
+    }
+}
+
+impl<'t> Iterator for Scanner<'_, 't> {
+    type Item = Token<'t>;
+
+    fn next(&mut self) -> Option<Token<'t>> {
+        while self.position < self.input.len() {
+            let start = self.position;
+            let i = &self.input[start..];
+
+            let matched = self.instructions.iter().find_map(|instruction| {
+                match_instruction(i, instruction).map(|(args, length)| (instruction, args, length))
+            });
+
+            if let Some((instruction, args, length)) = matched {
+                self.position += length;
+
+                return Some(Token {
+                    instruction,
+                    args,
+                    span: start..self.position,
+                });
+            }
+
+            self.position += 1;
+        }
+
+        None
+    }
+}
+
+/// Matches `name(a,b,...)` at the very start of `i`, returning the operands and the length in
+/// bytes.
+fn match_instruction(i: &[u8], instruction: &Instruction) -> Option<(Vec<u32>, usize)> {
+    let mut length = instruction.name.len();
+
+    if !i.starts_with(instruction.name.as_bytes()) || i.get(length) != Some(&b'(') {
+        return None;
+    }
+
+    length += 1;
+
+    let mut args = Vec::with_capacity(instruction.arity);
+
+    for n in 0..instruction.arity {
+        if n > 0 {
+            if i.get(length) != Some(&b',') {
+                return None;
+            }
+
+            length += 1;
+        }
+
+        let (arg, arg_length) = match_operand(&i[length..])?;
+        args.push(arg);
+        length += arg_length;
+    }
+
+    if i.get(length) != Some(&b')') {
+        return None;
+    }
+
+    Some((args, length + 1))
+}
+
+/// Matches a number of one to three digits.
+fn match_operand(i: &[u8]) -> Option<(u32, usize)> {
+    let length = i.iter().take(4).take_while(|b| b.is_ascii_digit()).count();
+
+    if !(1..=3).contains(&length) {
+        return None;
+    }
+
+    let value = i[..length]
+        .iter()
+        .fold(0, |value, digit| value * 10 + (digit - b'0') as u32);
+
+    Some((value, length))
+}
@@ -0,0 +1,23 @@
+use day_3::{marks, Mark};
+
+#[test]
+fn marks_instructions_and_near_misses() {
+    let program = day_3::parse("mul(2,4)don't()mul(4*mul(1,1)do()mul ( 2 , 4 )multiply").unwrap();
+    let source = &program.source;
+    let marks: Vec<_> = marks(&program)
+        .into_iter()
+        .map(|(span, mark)| (&source[span], mark))
+        .collect();
+
+    assert_eq!(
+        marks,
+        [
+            ("mul(2,4)", Mark::Enabled),
+            ("don't()", Mark::Control),
+            ("mul(4*", Mark::NearMiss),
+            ("mul(1,1)", Mark::Disabled),
+            ("do()", Mark::Control),
+            ("mul ( 2 , 4 )", Mark::NearMiss),
+        ]
+    );
+}
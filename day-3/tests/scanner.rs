@@ -0,0 +1,47 @@
+use day_3::{interpreter, run, Scanner, Status, EXTENDED, PUZZLE};
+
+#[test]
+fn tokens_have_byte_spans() {
+    let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+    let tokens: Vec<_> = Scanner::new(input, PUZZLE)
+        .map(|token| (token.to_string(), token.span))
+        .collect();
+
+    assert_eq!(
+        tokens,
+        [
+            ("mul(2,4)".to_owned(), 1..9),
+            ("don't()".to_owned(), 20..27),
+            ("mul(5,5)".to_owned(), 28..36),
+            ("mul(11,8)".to_owned(), 48..57),
+            ("do()".to_owned(), 59..63),
+            ("mul(8,5)".to_owned(), 64..72),
+        ]
+    );
+}
+
+#[test]
+fn extended_instructions() {
+    let input = "add(1,2)sub(3,10)toggle()mul(9,9)if(0)add(5,5)if(7)div(7,2)div(1,0)";
+    let tokens: Vec<_> = Scanner::new(input, EXTENDED).collect();
+    let run = run(&tokens);
+
+    assert_eq!(run.state.total, 3 - 7 + 3);
+    assert_eq!(
+        run.statuses,
+        [
+            Status::Executed,
+            Status::Executed,
+            Status::Executed,
+            Status::Skipped,
+            Status::Executed,
+            Status::Skipped,
+            Status::Executed,
+            Status::Executed,
+            Status::Failed("division by zero".to_owned()),
+        ]
+    );
+
+    // instructions outside the table are not recognized
+    assert_eq!(Scanner::new(input, &[interpreter::MUL]).count(), 1);
+}
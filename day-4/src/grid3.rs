@@ -0,0 +1,129 @@
+use aoc_core::{ParseError, Result};
+use aoc_grid::{Grid, ParseGridError, Point};
+
+use crate::search::{wrap_add, Board};
+
+/// A point in a 3D grid, by column, row and layer.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point3(pub usize, pub usize, pub usize);
+
+/// Every step to a neighbouring cell in 3D, layer by layer from the one above.
+pub static DIRECTIONS_26: [(isize, isize, isize); 26] = {
+    let mut directions = [(0, 0, 0); 26];
+    let mut i = 0;
+    let mut n = 0;
+
+    while n < 27 {
+        let direction = (n % 3 - 1, n / 3 % 3 - 1, n / 9 - 1);
+
+        if n != 13 {
+            directions[i] = direction;
+            i += 1;
+        }
+
+        n += 1;
+    }
+
+    directions
+};
+
+/// A stack of equally sized layers of characters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid3 {
+    layers: Vec<Grid<char>>,
+}
+
+impl Grid3 {
+    /// Reads layers of lines separated by blank lines.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut layers: Vec<Grid<char>> = vec![];
+        let mut lines = input.lines().enumerate().peekable();
+
+        while lines.peek().is_some() {
+            let first = lines.peek().map_or(0, |&(n, _)| n);
+            let mut layer = vec![];
+
+            for (_, line) in lines.by_ref() {
+                if line.is_empty() {
+                    break;
+                }
+
+                layer.push(line);
+            }
+
+            let grid = Grid::from_lines(layer.iter().copied(), |c| c).map_err(|e| {
+                let line = first + e.line;
+                ParseError::from_grid(input, ParseGridError { line, ..e })
+            })?;
+
+            if let Some(top) = layers.first() {
+                if (grid.width(), grid.height()) != (top.width(), top.height()) {
+                    return Err(ParseError::at_line(
+                        input,
+                        first + 1,
+                        1,
+                        format!("a layer of {} by {} cells", top.width(), top.height()),
+                    ));
+                }
+            }
+
+            layers.push(grid);
+        }
+
+        Ok(Grid3 { layers })
+    }
+
+    pub fn width(&self) -> usize {
+        self.layers.first().map_or(0, Grid::width)
+    }
+
+    pub fn height(&self) -> usize {
+        self.layers.first().map_or(0, Grid::height)
+    }
+
+    pub fn depth(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn layer(&self, z: usize) -> &Grid<char> {
+        &self.layers[z]
+    }
+}
+
+impl Board for Grid3 {
+    type Point = Point3;
+    type Direction = (isize, isize, isize);
+
+    fn directions(&self) -> &[(isize, isize, isize)] {
+        &DIRECTIONS_26
+    }
+
+    fn points(&self) -> impl Iterator<Item = Point3> {
+        self.layers
+            .iter()
+            .enumerate()
+            .flat_map(|(z, layer)| layer.points().map(move |Point(x, y)| Point3(x, y, z)))
+    }
+
+    fn cell(&self, Point3(x, y, z): Point3) -> char {
+        self.layers[z][Point(x, y)]
+    }
+
+    fn step(&self, point: Point3, direction: (isize, isize, isize), wrap: bool) -> Option<Point3> {
+        let Point3(x, y, z) = point;
+        let (dx, dy, dz) = direction;
+
+        if wrap {
+            return Some(Point3(
+                wrap_add(x, dx, self.width()),
+                wrap_add(y, dy, self.height()),
+                wrap_add(z, dz, self.depth()),
+            ));
+        }
+
+        let Point(x, y) = self.layers[z].translate(Point(x, y), (dx, dy))?;
+        let z = z.checked_add_signed(dz).filter(|&z| z < self.depth())?;
+
+        Some(Point3(x, y, z))
+    }
+}
@@ -0,0 +1,54 @@
+use aoc_core::{Answer, ParseError, Result, Solution};
+use aoc_grid::Grid;
+
+mod grid3;
+mod search;
+mod template;
+
+pub use grid3::{Grid3, Point3, DIRECTIONS_26};
+pub use search::{Board, Match, Overlap, WordSearch};
+pub use template::{Orientation, Placement, Template};
+
+pub type Input = Grid<char>;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    Grid::parse(input, |c| c).map_err(|e| ParseError::from_grid(input, e))
+}
+
+pub fn part1(grid: &Input) -> Answer {
+    count_xmas(grid).into()
+}
+
+pub fn part2(grid: &Input) -> Answer {
+    count_x_mas(grid).into()
+}
+
+fn count_xmas(grid: &Grid<char>) -> usize {
+    WordSearch::new(&["XMAS"])
+        .search(grid, Overlap::Allow)
+        .len()
+}
+
+fn count_x_mas(grid: &Grid<char>) -> usize {
+    Template::parse("M.S\n.A.\nM.S").unwrap().count(grid)
+}
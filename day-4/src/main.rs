@@ -1,116 +1,88 @@
-use std::{collections::HashMap, io::stdin, ops::Index};
+use std::{env, fs, process::exit};
 
-struct Grid {
-    width: usize,
-    height: usize,
-    cells: Vec<char>,
-}
-
-impl Index<&(usize, usize)> for Grid {
-    type Output = char;
-
-    fn index(&self, (x, y): &(usize, usize)) -> &Self::Output {
-        &self.cells[y * self.width + x]
-    }
-}
+use aoc_core::{normalize, Solution, Source};
+use day_4::{Board, Day4, Grid3, Overlap, WordSearch};
 
 fn main() {
-    let grid = parse_grid(stdin().lines().map(|l| l.unwrap()));
-
-    // part 1
-    println!("{}", count_xmas(&grid));
-
-    // part 2
-    println!("{}", count_x_mas(&grid));
-}
-
-fn parse_grid(lines: impl Iterator<Item = String>) -> Grid {
-    let mut grid = Grid {
-        width: 0,
-        height: 0,
-        cells: vec![],
-    };
-
-    for line in lines {
-        if grid.width == 0 {
-            grid.width = line.len();
-        } else {
-            assert_eq!(grid.width, line.len(), "inconsistent line length");
-        }
-
-        grid.height += 1;
-        grid.cells.extend(line.chars());
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args
+        .iter()
+        .any(|arg| matches!(arg.as_str(), "--words" | "--wrap" | "--3d"))
+    {
+        search(&args);
+    } else {
+        aoc_core::main::<Day4>();
     }
-
-    grid
 }
 
-fn count_xmas(grid: &Grid) -> usize {
-    let directions = &[(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
-    let xmas = &['X', 'M', 'A', 'S'];
-
-    let mut count = 0;
+/// Counts each word in a list, optionally wrapping around the edges or reading a 3D grid.
+fn search(args: &[String]) {
+    let usage = || -> ! {
+        eprintln!("usage: day-4 [--words <path>] [--wrap] [--3d] [--input <path|->]");
+        exit(2);
+    };
 
-    for y in 0..grid.height {
-        for x in 0..grid.width {
-            for direction in directions.iter() {
-                if let Some(_) = find(grid, &(x, y), direction, xmas) {
-                    count += 1;
-                }
-            }
+    let mut source = Source::detect(Day4::DAY);
+    let mut words_path = None;
+    let mut wrap = false;
+    let mut three_d = false;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--wrap" => wrap = true,
+            "--3d" => three_d = true,
+            "--input" => source = Source::from_arg(args.next().unwrap_or_else(|| usage())),
+            "--words" => words_path = Some(args.next().unwrap_or_else(|| usage())),
+            _ => usage(),
         }
     }
 
-    count
-}
+    let fail = |e: String| -> ! {
+        eprintln!("{}", e);
+        exit(1);
+    };
 
-fn count_x_mas(grid: &Grid) -> usize {
-    let directions = &[(-1, -1), (-1, 1), (1, -1), (1, 1)];
-    let mas = &['M', 'A', 'S'];
+    let words: Vec<String> = match words_path {
+        Some(path) => fs::read_to_string(path)
+            .map(|text| {
+                normalize(&text)
+                    .lines()
+                    .map(str::trim)
+                    .filter(|word| !word.is_empty())
+                    .map(str::to_owned)
+                    .collect()
+            })
+            .unwrap_or_else(|e| fail(format!("could not read {}: {}", path, e))),
+        None => vec!["XMAS".to_owned()],
+    };
 
-    let mut counts = HashMap::new();
+    let search = WordSearch::new(&words).wrapping(wrap);
+    let input = source
+        .read(Day4::DAY)
+        .unwrap_or_else(|e| fail(e.to_string()));
+
+    let counts = if three_d {
+        let grid = Grid3::parse(&input).unwrap_or_else(|e| fail(e.to_string()));
+        count(&search, &grid)
+    } else {
+        let grid = day_4::parse(&input).unwrap_or_else(|e| fail(e.to_string()));
+        count(&search, &grid)
+    };
 
-    for y in 0..grid.height {
-        for x in 0..grid.width {
-            for direction in directions.iter() {
-                if let Some([_, a, _]) = find(grid, &(x, y), direction, mas) {
-                    *counts.entry(a).or_insert(0) += 1;
-                }
-            }
-        }
+    for (word, count) in search.words().iter().zip(counts) {
+        println!("{} {}", word, count);
     }
-
-    counts.iter().filter(|(_, &count)| count > 1).count()
 }
 
-fn find<const N: usize>(
-    grid: &Grid,
-    origin: &(usize, usize),
-    step: &(isize, isize),
-    chars: &[char; N],
-) -> Option<[(usize, usize); N]> {
-    let mut path = [origin.to_owned(); N];
-
-    for index in 0..N {
-        let position = &mut path[index];
-
-        if index > 0 {
-            let (x, y) = position;
-            let (dx, dy) = step;
-
-            let (xo, yo);
-            (*x, xo) = x.overflowing_add_signed(dx * index as isize);
-            (*y, yo) = y.overflowing_add_signed(dy * index as isize);
+/// Matches of each word, in the order the words were given.
+fn count<B: Board>(search: &WordSearch, board: &B) -> Vec<usize> {
+    let mut counts = vec![0; search.words().len()];
 
-            if xo || *x >= grid.width || yo || *y >= grid.height {
-                return None;
-            }
-        }
-
-        if grid[position] != chars[index] {
-            return None;
-        }
+    for m in search.search(board, Overlap::Allow) {
+        counts[m.word] += 1;
     }
 
-    Some(path)
+    counts
 }
@@ -0,0 +1,244 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use aoc_grid::{Grid, Point, DIRECTIONS_8};
+
+/// Finds any number of words in a grid at once, reading in straight lines.
+pub struct WordSearch {
+    words: Vec<String>,
+    nodes: Vec<Node>,
+    wrap: bool,
+}
+
+/// Cells of characters that words can be read from in straight lines.
+pub trait Board {
+    type Point: Copy + Debug + Eq + Hash + Ord;
+    type Direction: Copy + Debug + Eq;
+
+    /// Every direction a word can be read in.
+    fn directions(&self) -> &[Self::Direction];
+
+    /// Every point, in reading order.
+    fn points(&self) -> impl Iterator<Item = Self::Point>;
+
+    fn cell(&self, point: Self::Point) -> char;
+
+    /// The next point in a direction, or `None` past the edge unless wrapping around to the
+    /// opposite edge.
+    fn step(
+        &self,
+        point: Self::Point,
+        direction: Self::Direction,
+        wrap: bool,
+    ) -> Option<Self::Point>;
+}
+
+/// A node of the trie of words, reached by reading the characters on the way to it.
+#[derive(Default)]
+struct Node {
+    children: HashMap<char, usize>,
+    /// Index of the word that ends here.
+    word: Option<usize>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match<P = Point, D = (isize, isize)> {
+    /// Index of the word in [`WordSearch::words`].
+    pub word: usize,
+    pub start: P,
+    pub direction: D,
+    /// The cells the word was read from, in order.
+    pub path: Vec<P>,
+}
+
+/// Which matches to keep when they share cells.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Overlap {
+    /// Keep every match.
+    #[default]
+    Allow,
+    /// Keep one match per set of cells, so a palindrome is not counted once in each direction.
+    DistinctCells,
+    /// Keep matches that share no cells with an earlier match, in reading order.
+    Disjoint,
+}
+
+impl WordSearch {
+    pub fn new<S: AsRef<str>>(words: &[S]) -> Self {
+        let mut search = WordSearch {
+            words: vec![],
+            nodes: vec![Node::default()],
+            wrap: false,
+        };
+
+        for word in words {
+            search.insert(word.as_ref());
+        }
+
+        search
+    }
+
+    /// Reads past the edges of the board, continuing from the opposite edge. A word never
+    /// reads the same cell twice.
+    pub fn wrapping(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// The distinct words searched for.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Finds every word in every direction of the board.
+    pub fn search<B: Board>(
+        &self,
+        board: &B,
+        overlap: Overlap,
+    ) -> Vec<Match<B::Point, B::Direction>> {
+        self.search_in(board, board.directions(), overlap)
+    }
+
+    /// Finds every word reading only in the given directions. Matches are ordered by start
+    /// point, then by direction.
+    pub fn search_in<B: Board>(
+        &self,
+        board: &B,
+        directions: &[B::Direction],
+        overlap: Overlap,
+    ) -> Vec<Match<B::Point, B::Direction>> {
+        let mut matches = vec![];
+
+        for start in board.points() {
+            for (index, &direction) in directions.iter().enumerate() {
+                self.walk(board, start, direction, index == 0, &mut matches);
+            }
+        }
+
+        match overlap {
+            Overlap::Allow => matches,
+            Overlap::DistinctCells => {
+                let mut seen = HashSet::new();
+
+                matches.retain(|m| {
+                    seen.insert((m.word, m.path.iter().copied().collect::<BTreeSet<_>>()))
+                });
+                matches
+            }
+            Overlap::Disjoint => {
+                let mut used = HashSet::new();
+
+                matches.retain(|m| {
+                    let free = m.path.iter().all(|p| !used.contains(p));
+
+                    if free {
+                        used.extend(m.path.iter().copied());
+                    }
+
+                    free
+                });
+                matches
+            }
+        }
+    }
+
+    fn insert(&mut self, word: &str) {
+        let mut node = 0;
+
+        for c in word.chars() {
+            node = match self.nodes[node].children.get(&c) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(Node::default());
+
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(c, child);
+                    child
+                }
+            };
+        }
+
+        // the empty word and repeated words are never matched
+        if node > 0 && self.nodes[node].word.is_none() {
+            self.nodes[node].word = Some(self.words.len());
+            self.words.push(word.to_owned());
+        }
+    }
+
+    /// Follows the trie along a line of the grid, collecting each word that ends on the way.
+    /// Single characters read the same in every direction, so they are only collected once.
+    fn walk<B: Board>(
+        &self,
+        board: &B,
+        start: B::Point,
+        direction: B::Direction,
+        single: bool,
+        matches: &mut Vec<Match<B::Point, B::Direction>>,
+    ) {
+        let mut node = 0;
+        let mut path = vec![];
+        let mut point = Some(start);
+
+        while let Some(p) = point {
+            let Some(&child) = self.nodes[node].children.get(&board.cell(p)) else {
+                break;
+            };
+
+            node = child;
+            path.push(p);
+
+            if let Some(word) = self.nodes[node].word {
+                if single || path.len() > 1 {
+                    matches.push(Match {
+                        word,
+                        start,
+                        direction,
+                        path: path.clone(),
+                    });
+                }
+            }
+
+            // wrapping around a line leads back to where it started
+            point = board
+                .step(p, direction, self.wrap)
+                .filter(|&next| next != start);
+        }
+    }
+}
+
+impl Board for Grid<char> {
+    type Point = Point;
+    type Direction = (isize, isize);
+
+    fn directions(&self) -> &[(isize, isize)] {
+        &DIRECTIONS_8
+    }
+
+    fn points(&self) -> impl Iterator<Item = Point> {
+        Grid::points(self)
+    }
+
+    fn cell(&self, point: Point) -> char {
+        self[point]
+    }
+
+    fn step(&self, point: Point, direction: (isize, isize), wrap: bool) -> Option<Point> {
+        if !wrap {
+            return self.translate(point, direction);
+        }
+
+        let Point(x, y) = point;
+        let (dx, dy) = direction;
+
+        Some(Point(
+            wrap_add(x, dx, self.width()),
+            wrap_add(y, dy, self.height()),
+        ))
+    }
+}
+
+/// Adds `delta` to `n`, wrapping around to stay within `0..size`.
+pub(crate) fn wrap_add(n: usize, delta: isize, size: usize) -> usize {
+    (n as isize + delta).rem_euclid(size as isize) as usize
+}
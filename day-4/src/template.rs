@@ -0,0 +1,99 @@
+use aoc_grid::{Grid, ParseGridError, Point};
+
+/// A small 2D pattern matched anywhere in a grid, turned and mirrored every way.
+pub struct Template {
+    /// The distinct orientations of the pattern, with `None` for cells that match anything.
+    orientations: Vec<(Orientation, Grid<Option<char>>)>,
+}
+
+/// How a pattern was turned to match: mirrored left to right first if `reflected`, then
+/// rotated clockwise by `quarter_turns`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Orientation {
+    pub quarter_turns: u8,
+    pub reflected: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Placement {
+    /// Top-left corner of the turned pattern in the grid.
+    pub position: Point,
+    pub orientation: Orientation,
+}
+
+impl Template {
+    /// Reads a pattern from lines of text, where `.` matches any character.
+    pub fn parse(pattern: &str) -> Result<Self, ParseGridError> {
+        let pattern = Grid::parse(pattern, |c| (c != '.').then_some(c))?;
+        Ok(Template::new(&pattern))
+    }
+
+    /// Builds the template from all 4 rotations of the pattern and of its mirror image.
+    /// Orientations that look the same as an earlier one are dropped, so a symmetric pattern
+    /// matches each place only once.
+    pub fn new(pattern: &Grid<Option<char>>) -> Self {
+        let mut orientations: Vec<(Orientation, Grid<Option<char>>)> = vec![];
+
+        for reflected in [false, true] {
+            let mut turned = if reflected {
+                pattern.flip_horizontal()
+            } else {
+                pattern.clone()
+            };
+
+            for quarter_turns in 0..4 {
+                if !orientations.iter().any(|(_, o)| o == &turned) {
+                    let orientation = Orientation {
+                        quarter_turns,
+                        reflected,
+                    };
+
+                    orientations.push((orientation, turned.clone()));
+                }
+
+                turned = turned.rotate_clockwise();
+            }
+        }
+
+        Template { orientations }
+    }
+
+    pub fn orientations(&self) -> impl Iterator<Item = Orientation> + '_ {
+        self.orientations
+            .iter()
+            .map(|&(orientation, _)| orientation)
+    }
+
+    /// Finds every place and orientation the pattern matches, ordered by position.
+    pub fn find(&self, grid: &Grid<char>) -> Vec<Placement> {
+        let mut placements = vec![];
+
+        for position in grid.points() {
+            for (orientation, pattern) in &self.orientations {
+                if matches_at(grid, pattern, position) {
+                    placements.push(Placement {
+                        position,
+                        orientation: *orientation,
+                    });
+                }
+            }
+        }
+
+        placements
+    }
+
+    pub fn count(&self, grid: &Grid<char>) -> usize {
+        self.find(grid).len()
+    }
+}
+
+fn matches_at(grid: &Grid<char>, pattern: &Grid<Option<char>>, Point(x, y): Point) -> bool {
+    if x + pattern.width() > grid.width() || y + pattern.height() > grid.height() {
+        return false;
+    }
+
+    pattern.iter().all(|(Point(dx, dy), cell)| match cell {
+        Some(c) => grid[Point(x + dx, y + dy)] == *c,
+        None => true,
+    })
+}
@@ -0,0 +1,114 @@
+use aoc_grid::Point;
+use day_4::{Grid3, Overlap, Point3, WordSearch, DIRECTIONS_26};
+
+fn grid(rows: &str) -> aoc_grid::Grid<char> {
+    day_4::parse(rows).unwrap()
+}
+
+#[test]
+fn finds_many_words_in_every_direction() {
+    let grid = grid("ABAX\nZZZM\nZZZA\nZZZS");
+    let search = WordSearch::new(&["XMAS", "XM", "ABA", "SAMX", "XMAS", "Q"]);
+
+    assert_eq!(search.words(), ["XMAS", "XM", "ABA", "SAMX", "Q"]);
+
+    let found: Vec<_> = search
+        .search(&grid, Overlap::Allow)
+        .into_iter()
+        .map(|m| (search.words()[m.word].as_str(), m.start, m.direction))
+        .collect();
+
+    assert_eq!(
+        found,
+        [
+            ("ABA", Point(0, 0), (1, 0)),
+            ("ABA", Point(2, 0), (-1, 0)),
+            ("XM", Point(3, 0), (0, 1)),
+            ("XMAS", Point(3, 0), (0, 1)),
+            ("SAMX", Point(3, 3), (0, -1)),
+        ]
+    );
+}
+
+#[test]
+fn overlap_rules() {
+    let grid = grid("ABAX\nZZZM\nZZZA\nZZZS");
+    let search = WordSearch::new(&["ABA", "AX", "XMAS"]);
+    let count = |overlap| search.search(&grid, overlap).len();
+
+    assert_eq!(count(Overlap::Allow), 4);
+    // the palindrome is kept once
+    assert_eq!(count(Overlap::DistinctCells), 3);
+    // "AX" shares its A with the first "ABA"
+    assert_eq!(count(Overlap::Disjoint), 2);
+
+    let paths: Vec<_> = search
+        .search(&grid, Overlap::Disjoint)
+        .into_iter()
+        .map(|m| m.path)
+        .collect();
+
+    assert_eq!(
+        paths,
+        [
+            vec![Point(0, 0), Point(1, 0), Point(2, 0)],
+            vec![Point(3, 0), Point(3, 1), Point(3, 2), Point(3, 3)],
+        ]
+    );
+}
+
+#[test]
+fn wraps_around_edges() {
+    let grid = grid("MASX\nZZZZ");
+    let search = WordSearch::new(&["XMAS", "XZX"]);
+
+    let found: Vec<_> = search
+        .wrapping(true)
+        .search(&grid, Overlap::Allow)
+        .into_iter()
+        .map(|m| (m.start, m.direction, m.path))
+        .collect();
+
+    // "XZX" would read the X twice, going down a column of two
+    assert_eq!(
+        found,
+        [(
+            Point(3, 0),
+            (1, 0),
+            vec![Point(3, 0), Point(0, 0), Point(1, 0), Point(2, 0)]
+        )]
+    );
+
+    assert!(WordSearch::new(&["XMAS"])
+        .search(&grid, Overlap::Allow)
+        .is_empty());
+}
+
+#[test]
+fn searches_layers_in_26_directions() {
+    let cube = Grid3::parse("XZZ\nZZZ\nZZZ\n\nZZZ\nZMZ\nZZZ\n\nZZZ\nZZZ\nZZA").unwrap();
+
+    assert_eq!((cube.width(), cube.height(), cube.depth()), (3, 3, 3));
+    assert_eq!(DIRECTIONS_26.len(), 26);
+
+    let found: Vec<_> = WordSearch::new(&["XMA", "XMAS"])
+        .search(&cube, Overlap::Allow)
+        .into_iter()
+        .map(|m| (m.start, m.direction, m.path))
+        .collect();
+
+    assert_eq!(
+        found,
+        [(
+            Point3(0, 0, 0),
+            (1, 1, 1),
+            vec![Point3(0, 0, 0), Point3(1, 1, 1), Point3(2, 2, 2)]
+        )]
+    );
+}
+
+#[test]
+fn rejects_uneven_layers() {
+    let e = Grid3::parse("AB\nCD\n\nABC\nDEF").unwrap_err();
+    assert!(e.to_string().contains("line 4"), "{}", e);
+}
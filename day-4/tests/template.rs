@@ -0,0 +1,58 @@
+use aoc_grid::Point;
+use day_4::{Orientation, Placement, Template};
+
+#[test]
+fn symmetric_orientations_are_dropped() {
+    let count = |pattern| Template::parse(pattern).unwrap().orientations().count();
+
+    assert_eq!(count("M.S\n.A.\nM.S"), 4);
+    assert_eq!(count("A.A\n.A.\nA.A"), 1);
+    assert_eq!(count("AB"), 4);
+    assert_eq!(count("AB\nC."), 8);
+}
+
+#[test]
+fn finds_turned_and_mirrored_patterns() {
+    let grid = day_4::parse("XAB\nXCX\nBXX\nAC.").unwrap();
+    let template = Template::parse("AB\nC.").unwrap();
+
+    assert_eq!(
+        template.find(&grid),
+        [
+            Placement {
+                position: Point(1, 0),
+                orientation: Orientation {
+                    quarter_turns: 0,
+                    reflected: false,
+                },
+            },
+            Placement {
+                position: Point(0, 2),
+                orientation: Orientation {
+                    quarter_turns: 3,
+                    reflected: false,
+                },
+            },
+        ]
+    );
+}
+
+#[test]
+fn finds_mirror_images() {
+    let grid = day_4::parse("BA\nXC").unwrap();
+    let template = Template::parse("AB\nC.").unwrap();
+
+    let orientations: Vec<_> = template
+        .find(&grid)
+        .into_iter()
+        .map(|p| p.orientation)
+        .collect();
+
+    assert_eq!(
+        orientations,
+        [Orientation {
+            quarter_turns: 0,
+            reflected: true,
+        }]
+    );
+}
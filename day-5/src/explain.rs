@@ -0,0 +1,181 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::order::{topological_order, Cycle};
+
+/// A rule `before|after` broken by an update, with the indices of its two pages in the update.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Violation<T> {
+    pub before: T,
+    pub after: T,
+    pub positions: (usize, usize),
+}
+
+/// A page taken out of an update and put back at another index.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Move<T> {
+    pub page: T,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// Every rule directly between two of the update's pages that the update breaks, in the order
+/// of the later page's index.
+pub fn violations<T>(rules: &HashMap<T, HashSet<T>>, pages: &[T]) -> Vec<Violation<T>>
+where
+    T: Copy + Eq + Hash,
+{
+    let mut violations = vec![];
+
+    for (j, &after) in pages.iter().enumerate() {
+        for (i, &before) in pages.iter().enumerate().skip(j + 1) {
+            if rules.get(&before).is_some_and(|s| s.contains(&after)) {
+                violations.push(Violation {
+                    before,
+                    after,
+                    positions: (i, j),
+                });
+            }
+        }
+    }
+
+    violations
+}
+
+/// The fewest pages to move to make the update follow the rules, with the index each ends up
+/// at. The pages left in place are the most that are already in an order the rules allow,
+/// including through pages between them.
+pub fn minimal_moves<T>(
+    rules: &HashMap<T, HashSet<T>>,
+    pages: &[T],
+) -> Result<Vec<Move<T>>, Cycle<T>>
+where
+    T: Copy + Eq + Hash,
+{
+    topological_order(rules, pages)?;
+
+    let kept = kept(rules, pages);
+
+    // keep the pages left in place in their order while ordering the rest around them
+    let mut pinned = rules.clone();
+    let kept_pages: Vec<_> = (0..pages.len())
+        .filter(|&i| kept[i])
+        .map(|i| pages[i])
+        .collect();
+
+    for pair in kept_pages.windows(2) {
+        pinned.entry(pair[0]).or_default().insert(pair[1]);
+    }
+
+    let order = topological_order(&pinned, pages)?.pages;
+
+    Ok((0..pages.len())
+        .filter(|&i| !kept[i])
+        .map(|from| Move {
+            page: pages[from],
+            from,
+            to: order.iter().position(|p| *p == pages[from]).unwrap(),
+        })
+        .collect())
+}
+
+/// Finds the most pages that can stay where they are. Page `i` conflicts with a later page `j`
+/// when the rules put `j` before `i`, even through other pages. Conflicts are transitive, so the
+/// most pages without a conflict between any two is the size of the update less the most pairs
+/// that can be matched along conflicts, and the pages are found from the matching.
+fn kept<T>(rules: &HashMap<T, HashSet<T>>, pages: &[T]) -> Vec<bool>
+where
+    T: Copy + Eq + Hash,
+{
+    let n = pages.len();
+    let conflicts: Vec<Vec<usize>> = (0..n)
+        .map(|i| {
+            let reached = reachable(rules, pages, i);
+            (0..i).filter(|&j| reached[j]).collect()
+        })
+        .collect();
+
+    // conflicts[i] lists the earlier pages that page i must come before
+    let mut matched: Vec<Option<usize>> = vec![None; n];
+
+    for i in 0..n {
+        augment(&conflicts, i, &mut vec![false; n], &mut matched);
+    }
+
+    let mut is_matched = vec![false; n];
+
+    for &i in matched.iter().flatten() {
+        is_matched[i] = true;
+    }
+
+    // pages reachable by alternating paths from the unmatched ones on the left
+    let mut left = vec![false; n];
+    let mut right = vec![false; n];
+    let mut stack: Vec<_> = (0..n).filter(|&i| !is_matched[i]).collect();
+
+    while let Some(i) = stack.pop() {
+        if left[i] {
+            continue;
+        }
+
+        left[i] = true;
+
+        for &j in &conflicts[i] {
+            if !right[j] {
+                right[j] = true;
+                stack.extend(matched[j]);
+            }
+        }
+    }
+
+    (0..n).map(|i| left[i] && !right[i]).collect()
+}
+
+/// Looks for an augmenting path from `i`, matching it with one of its conflicts.
+fn augment(
+    conflicts: &[Vec<usize>],
+    i: usize,
+    visited: &mut [bool],
+    matched: &mut [Option<usize>],
+) -> bool {
+    for &j in &conflicts[i] {
+        if visited[j] {
+            continue;
+        }
+
+        visited[j] = true;
+
+        if matched[j].is_none_or(|k| augment(conflicts, k, visited, matched)) {
+            matched[j] = Some(i);
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Which of the update's pages must come after page `from`, following rules between its pages.
+fn reachable<T>(rules: &HashMap<T, HashSet<T>>, pages: &[T], from: usize) -> Vec<bool>
+where
+    T: Copy + Eq + Hash,
+{
+    let mut reached = vec![false; pages.len()];
+    let mut stack = vec![from];
+
+    while let Some(i) = stack.pop() {
+        let Some(after) = rules.get(&pages[i]) else {
+            continue;
+        };
+
+        for (j, page) in pages.iter().enumerate() {
+            if !reached[j] && after.contains(page) {
+                reached[j] = true;
+                stack.push(j);
+            }
+        }
+    }
+
+    reached
+}
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+/// A page, by its index among the labels of an [`Interner`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Page(pub u32);
+
+/// Gives each distinct page label, such as a page number or a package name, its own [`Page`],
+/// so pages can be copied and compared cheaply.
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    labels: Vec<String>,
+    pages: HashMap<String, Page>,
+}
+
+impl Interner {
+    /// The page for a label, adding it if it is new.
+    pub fn intern(&mut self, label: &str) -> Page {
+        if let Some(&page) = self.pages.get(label) {
+            return page;
+        }
+
+        let page = Page(self.labels.len() as u32);
+        self.labels.push(label.to_owned());
+        self.pages.insert(label.to_owned(), page);
+        page
+    }
+
+    pub fn get(&self, label: &str) -> Option<Page> {
+        self.pages.get(label).copied()
+    }
+
+    pub fn label(&self, page: Page) -> &str {
+        &self.labels[page.0 as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
@@ -0,0 +1,285 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    hash::Hash,
+};
+
+use aoc_core::{parse_all, Answer, IResult, ParseError, Result, Solution};
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::newline,
+    multi::{many1, separated_list0, separated_list1},
+    sequence::separated_pair,
+};
+
+mod explain;
+mod interner;
+mod order;
+mod rule_set;
+
+pub use explain::{minimal_moves, violations, Move, Violation};
+pub use interner::{Interner, Page};
+pub use order::{topological_order, Cycle, Order};
+pub use rule_set::RuleSet;
+
+pub type Rules = HashMap<Page, HashSet<Page>>;
+
+/// The ordering rules and the updates, with pages interned from their labels.
+#[derive(Debug)]
+pub struct Manual {
+    pub rules: RuleSet,
+    pub updates: Vec<Vec<Page>>,
+}
+
+pub type Input = Manual;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+/// Parses the rules and updates, rejecting any update whose pages the rules cannot order. Pages
+/// can be labelled by anything without whitespace, `|` or `,`.
+pub fn parse(input: &str) -> Result<Input> {
+    let (rule_labels, update_labels) = parse_all(input, parse_input)?;
+
+    let mut rules = RuleSet::default();
+
+    for (before, after) in rule_labels {
+        rules.add(before, after);
+    }
+
+    let updates: Vec<Vec<_>> = update_labels
+        .iter()
+        .map(|update| update.iter().map(|label| rules.intern(label)).collect())
+        .collect();
+
+    let update_lines = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.contains('|'));
+
+    for (update, (line, _)) in updates.iter().zip(update_lines) {
+        if let Err(cycle) = rules.order(update) {
+            return Err(ParseError::at_line(
+                input,
+                line + 1,
+                1,
+                format!(
+                    "an update the rules can order, but they form a cycle {}",
+                    Cycle(rules.labels(&cycle.0))
+                ),
+            ));
+        }
+    }
+
+    Ok(Manual { rules, updates })
+}
+
+pub fn part1(manual: &Input) -> Answer {
+    let valid = manual
+        .updates
+        .iter()
+        .filter(|u| manual.rules.is_ordered(u))
+        .cloned();
+
+    sum_middle_pages(manual, valid)
+}
+
+pub fn part2(manual: &Input) -> Answer {
+    let reordered = manual
+        .updates
+        .iter()
+        .map(|u| (u, order(&manual.rules, u).pages))
+        .filter(|(u, ordered)| ordered != *u)
+        .map(|(_, ordered)| ordered);
+
+    sum_middle_pages(manual, reordered)
+}
+
+/// Adds up the middle page of each update. If any of them is not labelled by a number, lists
+/// their labels instead.
+fn sum_middle_pages(manual: &Input, updates: impl Iterator<Item = Vec<Page>>) -> Answer {
+    let middle: Vec<_> = updates
+        .map(|u| manual.rules.label(u[u.len() / 2]))
+        .collect();
+
+    let numbers: Result<Vec<u64>, _> = middle.iter().map(|label| label.parse()).collect();
+
+    match numbers {
+        Ok(numbers) => numbers.iter().map(|&n| n as u128).sum::<u128>().into(),
+        Err(_) => Answer::Text(middle.join(",")),
+    }
+}
+
+/// Lists each update's pages in the order the rules give them, noting updates that were out of
+/// order and those the rules do not order completely.
+pub fn orders(manual: &Input) -> String {
+    let mut output = String::new();
+
+    for (n, update) in manual.updates.iter().enumerate() {
+        writeln!(
+            output,
+            "update {}: {}",
+            n + 1,
+            describe_order(&manual.rules, update)
+        )
+        .unwrap();
+    }
+
+    output
+}
+
+/// Describes how the rules order an update, or the cycle that stops them.
+pub fn describe_order(rules: &RuleSet, update: &[Page]) -> String {
+    match rules.order(update) {
+        Ok(Order { pages, unique }) => format!(
+            "{}{}{}",
+            rules.labels(&pages).join(","),
+            if pages != update { " (reordered)" } else { "" },
+            if unique { "" } else { " (not unique)" }
+        ),
+        Err(cycle) => format!("rules form a cycle {}", Cycle(rules.labels(&cycle.0))),
+    }
+}
+
+/// Lists the rules each out-of-order update breaks, with where their pages are, and the fewest
+/// page moves that would put it in order.
+pub fn explain(manual: &Input) -> String {
+    let rules = &manual.rules;
+    let mut output = String::new();
+
+    for (n, update) in manual.updates.iter().enumerate() {
+        let violations = rules.violations(update);
+
+        if violations.is_empty() {
+            writeln!(output, "update {}: in order", n + 1).unwrap();
+            continue;
+        }
+
+        writeln!(
+            output,
+            "update {}: {} {} broken",
+            n + 1,
+            violations.len(),
+            if violations.len() == 1 {
+                "rule"
+            } else {
+                "rules"
+            }
+        )
+        .unwrap();
+
+        for Violation {
+            before,
+            after,
+            positions: (i, j),
+        } in violations
+        {
+            let (before, after) = (rules.label(before), rules.label(after));
+
+            writeln!(
+                output,
+                "  {}|{}: {} at index {}, {} at index {}",
+                before, after, before, i, after, j
+            )
+            .unwrap();
+        }
+
+        let moves = rules
+            .minimal_moves(update)
+            .expect("cycles are rejected when parsing");
+
+        for Move { page, from, to } in moves {
+            writeln!(
+                output,
+                "  move {} from index {} to {}",
+                rules.label(page),
+                from,
+                to
+            )
+            .unwrap();
+        }
+    }
+
+    output
+}
+
+fn order(rules: &RuleSet, update: &[Page]) -> Order<Page> {
+    rules
+        .order(update)
+        .expect("cycles are rejected when parsing")
+}
+
+fn parse_label(i: &str) -> IResult<'_, &str> {
+    take_while1(|c: char| !matches!(c, '|' | ',') && !c.is_whitespace())(i)
+}
+
+fn parse_rule(i: &str) -> IResult<'_, (&str, &str)> {
+    separated_pair(parse_label, tag("|"), parse_label)(i)
+}
+
+pub(crate) fn parse_rules(i: &str) -> IResult<'_, Vec<(&str, &str)>> {
+    separated_list0(newline, parse_rule)(i)
+}
+
+pub(crate) fn parse_update(i: &str) -> IResult<'_, Vec<&str>> {
+    separated_list1(tag(","), parse_label)(i)
+}
+
+fn parse_updates(i: &str) -> IResult<'_, Vec<Vec<&str>>> {
+    separated_list0(newline, parse_update)(i)
+}
+
+#[allow(clippy::type_complexity)]
+fn parse_input(i: &str) -> IResult<'_, (Vec<(&str, &str)>, Vec<Vec<&str>>)> {
+    let (i, rules) = parse_rules(i)?;
+    let (i, _) = many1(newline)(i)?;
+    let (i, updates) = parse_updates(i)?;
+
+    Ok((i, (rules, updates)))
+}
+
+/// Compares two pages by the rule directly between them, if any. This is not transitive when
+/// the rules are incomplete, so it cannot be used to sort; see [`topological_order`].
+pub fn comparator<T>(rules: &HashMap<T, HashSet<T>>) -> impl Fn(&T, &T) -> Ordering + use<'_, T>
+where
+    T: Eq + Hash,
+{
+    move |a, b| {
+        if rules.get(a).is_some_and(|s| s.contains(b)) {
+            return Ordering::Less;
+        }
+
+        if rules.get(b).is_some_and(|s| s.contains(a)) {
+            return Ordering::Greater;
+        }
+
+        Ordering::Equal
+    }
+}
+
+/// Whether `a` may come before `b` according to `comparator`, as for `is_sorted_by`.
+pub fn predicate<T, F>(comparator: &F) -> impl Fn(&T, &T) -> bool + use<'_, T, F>
+where
+    T: Eq + Hash,
+    F: Fn(&T, &T) -> Ordering,
+{
+    move |a, b| comparator(a, b) != Ordering::Greater
+}
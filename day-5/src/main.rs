@@ -1,95 +1,80 @@
-use std::{
-    cmp::Ordering,
-    collections::{HashMap, HashSet},
-    hash::Hash,
-    io::{stdin, Read},
-};
+use std::{env, fs, io::BufRead, process::exit};
 
-use nom::{
-    bytes::complete::tag,
-    character::complete::{newline, u8},
-    multi::{many1, separated_list0, separated_list1},
-    sequence::separated_pair,
-    IResult,
-};
+use aoc_core::{Solution, Source};
+use day_5::{describe_order, Day5, RuleSet};
 
 fn main() {
-    let mut buffer = String::new();
-    stdin().read_to_string(&mut buffer).unwrap();
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    let (_, (rules, updates)) = parse_input(&buffer).expect("malformed input");
-    let comparator = comparator(&rules);
-
-    let (valid, mut invalid): (Vec<_>, Vec<_>) = updates
-        .into_iter()
-        .partition(|u| u.is_sorted_by(predicate(&comparator)));
-
-    // part 1
-    let valid_mid_sum = valid.iter().fold(0, |sum, u| sum + u[u.len() / 2] as u32);
-    println!("{}", valid_mid_sum);
-
-    // part 2
-    for update in invalid.iter_mut() {
-        update.sort_by(&comparator);
+    if args.iter().any(|arg| arg == "--rules") {
+        check_updates(&args);
+    } else {
+        aoc_core::main_with::<Day5>(&[("--orders", day_5::orders), ("--explain", day_5::explain)]);
     }
-
-    let reordered_mid_sum = invalid.iter().fold(0, |sum, u| sum + u[u.len() / 2] as u32);
-    println!("{}", reordered_mid_sum);
 }
 
-fn parse_rule(i: &str) -> IResult<&str, (u8, u8)> {
-    separated_pair(u8, tag("|"), u8)(i)
-}
+/// Checks updates, one per line of the input, against rules read from a separate file.
+fn check_updates(args: &[String]) {
+    let usage = || -> ! {
+        eprintln!("usage: day-5 --rules <path> [--input <path|->]");
+        exit(2);
+    };
 
-fn parse_rules(i: &str) -> IResult<&str, HashMap<u8, HashSet<u8>>> {
-    separated_list0(newline, parse_rule)(i).map(|(i, rules)| {
-        (
-            i,
-            rules.iter().fold(HashMap::new(), |mut map, (a, b)| {
-                map.entry(*a).or_insert_with(HashSet::new).insert(*b);
-                map
-            }),
-        )
-    })
-}
+    let mut source = Source::detect(Day5::DAY);
+    let mut rules_path = None;
+    let mut args = args.iter();
 
-fn parse_update(i: &str) -> IResult<&str, Vec<u8>> {
-    separated_list1(tag(","), u8)(i)
-}
+    while let Some(arg) = args.next() {
+        let value = args.next().unwrap_or_else(|| usage());
 
-fn parse_updates(i: &str) -> IResult<&str, Vec<Vec<u8>>> {
-    separated_list0(newline, parse_update)(i)
-}
+        match arg.as_str() {
+            "--input" => source = Source::from_arg(value),
+            "--rules" => rules_path = Some(value),
+            _ => usage(),
+        }
+    }
 
-fn parse_input(i: &str) -> IResult<&str, (HashMap<u8, HashSet<u8>>, Vec<Vec<u8>>)> {
-    let (i, rules) = parse_rules(i)?;
-    let (i, _) = many1(newline)(i)?;
-    let (i, updates) = parse_updates(i)?;
+    let fail = |e: String| -> ! {
+        eprintln!("{}", e);
+        exit(1);
+    };
 
-    Ok((i, (rules, updates)))
-}
+    let rules = fs::read_to_string(rules_path.unwrap_or_else(|| usage()))
+        .map_err(|e| e.to_string())
+        .and_then(|text| RuleSet::parse(&text).map_err(|e| e.to_string()));
 
-fn comparator<T>(rules: &HashMap<T, HashSet<T>>) -> impl Fn(&T, &T) -> Ordering + use<'_, T>
-where
-    T: Eq + Hash,
-{
-    move |a, b| {
-        if rules.get(a).map_or(false, |s| s.contains(b)) {
-            return Ordering::Less;
-        }
+    let mut rules = rules.unwrap_or_else(|e| fail(e));
+    let reader = source
+        .open(Day5::DAY)
+        .unwrap_or_else(|e| fail(e.to_string()));
+    let mut failed = false;
 
-        if rules.get(b).map_or(false, |s| s.contains(a)) {
-            return Ordering::Greater;
+    for (n, line) in reader.lines().enumerate() {
+        let line = line.unwrap_or_else(|e| fail(e.to_string()));
+
+        if line.trim_start_matches('\u{feff}').trim().is_empty() {
+            continue;
         }
 
-        return Ordering::Equal;
+        match rules.parse_update(&line) {
+            Ok(update) => {
+                let described = describe_order(&rules, &update);
+                failed |= rules.order(&update).is_err();
+                println!("line {}: {}", n + 1, described);
+            }
+            Err(e) => {
+                failed = true;
+                println!(
+                    "line {}, column {}: expected {}",
+                    n + 1,
+                    e.column(),
+                    e.expected()
+                );
+            }
+        }
     }
-}
 
-fn predicate<T, F>(comparator: &F) -> impl Fn(&T, &T) -> bool + use<'_, T, F>
-where
-    T: Eq + Hash,
-    F: Fn(&T, &T) -> Ordering,
-{
-    move |a, b| comparator(a, b) != Ordering::Greater
+    if failed {
+        exit(1);
+    }
 }
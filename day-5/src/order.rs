@@ -0,0 +1,99 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    error::Error,
+    fmt::{self, Display},
+    hash::Hash,
+};
+
+/// An order of an update's pages that follows every rule between them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Order<T> {
+    pub pages: Vec<T>,
+    /// Whether this is the only order that follows the rules. When it is not, pages the rules
+    /// leave unordered keep their order from the update.
+    pub unique: bool,
+}
+
+/// Rules that contradict each other for an update's pages, as a cycle of pages where each must
+/// come before the next and the last before the first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cycle<T>(pub Vec<T>);
+
+/// Orders an update's pages by the rules between them, ignoring rules about other pages.
+pub fn topological_order<T>(
+    rules: &HashMap<T, HashSet<T>>,
+    pages: &[T],
+) -> Result<Order<T>, Cycle<T>>
+where
+    T: Copy + Eq + Hash,
+{
+    let n = pages.len();
+    let mut successors = vec![vec![]; n];
+    let mut predecessors = vec![vec![]; n];
+
+    for (i, a) in pages.iter().enumerate() {
+        for (j, b) in pages.iter().enumerate() {
+            if rules.get(a).is_some_and(|s| s.contains(b)) {
+                successors[i].push(j);
+                predecessors[j].push(i);
+            }
+        }
+    }
+
+    let mut in_degree: Vec<_> = predecessors.iter().map(Vec::len).collect();
+    let mut ready: BTreeSet<_> = (0..n).filter(|&i| in_degree[i] == 0).collect();
+    let mut order = Vec::with_capacity(n);
+    let mut unique = true;
+
+    // taking the earliest ready page first leaves an update that follows the rules unchanged
+    while let Some(i) = ready.pop_first() {
+        unique &= ready.is_empty();
+        order.push(pages[i]);
+
+        for &j in &successors[i] {
+            in_degree[j] -= 1;
+
+            if in_degree[j] == 0 {
+                ready.insert(j);
+            }
+        }
+    }
+
+    if order.len() == n {
+        return Ok(Order {
+            pages: order,
+            unique,
+        });
+    }
+
+    // every page left over has a predecessor also left over, so following them must loop
+    let mut path = vec![];
+    let mut seen = vec![None; n];
+    let mut i = (0..n).find(|&i| in_degree[i] > 0).unwrap();
+
+    while seen[i].is_none() {
+        seen[i] = Some(path.len());
+        path.push(i);
+        i = *predecessors[i].iter().find(|&&p| in_degree[p] > 0).unwrap();
+    }
+
+    let start = seen[i].unwrap();
+    let cycle = path[start..].iter().rev().map(|&i| pages[i]).collect();
+
+    Err(Cycle(cycle))
+}
+
+impl<T: Display> Display for Cycle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for page in &self.0 {
+            write!(f, "{} -> ", page)?;
+        }
+
+        match self.0.first() {
+            Some(first) => write!(f, "{}", first),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Display + fmt::Debug> Error for Cycle<T> {}
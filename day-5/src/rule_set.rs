@@ -0,0 +1,150 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+
+use aoc_core::{normalize, parse_all, Result};
+
+use crate::{
+    explain::{minimal_moves, violations, Move, Violation},
+    interner::{Interner, Page},
+    order::{topological_order, Cycle, Order},
+    parse_rules, parse_update, Rules,
+};
+
+/// Ordering rules loaded once, to check and reorder any number of updates against. Rules can be
+/// added and removed as it is used.
+#[derive(Debug, Default)]
+pub struct RuleSet {
+    pages: Interner,
+    rules: Rules,
+    /// Every page each page must come before through chains of rules, worked out when first
+    /// asked for.
+    closure: RefCell<HashMap<Page, HashSet<Page>>>,
+}
+
+impl RuleSet {
+    /// Reads rules of the form `a|b`, one per line, however the file's lines end.
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = normalize(input);
+        let mut rule_set = RuleSet::default();
+
+        for (before, after) in parse_all(&input, parse_rules)? {
+            rule_set.add(before, after);
+        }
+
+        Ok(rule_set)
+    }
+
+    /// Reads an update of comma-separated pages, giving new pages a label of their own. A byte
+    /// order mark or carriage return left on a line read from a file is ignored.
+    pub fn parse_update(&mut self, input: &str) -> Result<Vec<Page>> {
+        let input = input.trim_start_matches('\u{feff}').trim_end_matches('\r');
+        let labels = parse_all(input, parse_update)?;
+        Ok(labels.iter().map(|label| self.intern(label)).collect())
+    }
+
+    /// The page for a label, adding it if it is new.
+    pub fn intern(&mut self, label: &str) -> Page {
+        self.pages.intern(label)
+    }
+
+    pub fn pages(&self) -> &Interner {
+        &self.pages
+    }
+
+    /// The rules as given, from each page to the pages it must come before.
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    pub fn label(&self, page: Page) -> &str {
+        self.pages.label(page)
+    }
+
+    pub fn labels(&self, pages: &[Page]) -> Vec<&str> {
+        pages.iter().map(|&page| self.label(page)).collect()
+    }
+
+    /// Adds the rule `before|after`, returning whether it is new.
+    pub fn add(&mut self, before: &str, after: &str) -> bool {
+        let (before, after) = (self.pages.intern(before), self.pages.intern(after));
+        let added = self.rules.entry(before).or_default().insert(after);
+
+        if added {
+            self.invalidate(before);
+        }
+
+        added
+    }
+
+    /// Removes the rule `before|after`, returning whether there was one.
+    pub fn remove(&mut self, before: &str, after: &str) -> bool {
+        let (Some(before), Some(after)) = (self.pages.get(before), self.pages.get(after)) else {
+            return false;
+        };
+
+        let removed = self
+            .rules
+            .get_mut(&before)
+            .is_some_and(|s| s.remove(&after));
+
+        if removed {
+            self.invalidate(before);
+        }
+
+        removed
+    }
+
+    /// Whether some chain of rules puts `before` ahead of `after`, through any pages. Updates
+    /// only follow rules between their own pages, so this does not mean every update must.
+    pub fn implies(&self, before: Page, after: Page) -> bool {
+        let mut closure = self.closure.borrow_mut();
+
+        closure
+            .entry(before)
+            .or_insert_with(|| self.reachable(before))
+            .contains(&after)
+    }
+
+    /// Orders an update's pages by the rules between them.
+    pub fn order(&self, update: &[Page]) -> Result<Order<Page>, Cycle<Page>> {
+        topological_order(&self.rules, update)
+    }
+
+    /// Whether the update already follows the rules between its pages.
+    pub fn is_ordered(&self, update: &[Page]) -> bool {
+        violations(&self.rules, update).is_empty()
+    }
+
+    pub fn violations(&self, update: &[Page]) -> Vec<Violation<Page>> {
+        violations(&self.rules, update)
+    }
+
+    pub fn minimal_moves(&self, update: &[Page]) -> Result<Vec<Move<Page>>, Cycle<Page>> {
+        minimal_moves(&self.rules, update)
+    }
+
+    /// Forgets the closures that lead through `page`, which are all that a change to the rules
+    /// from `page` can affect.
+    fn invalidate(&mut self, page: Page) {
+        self.closure
+            .get_mut()
+            .retain(|&from, reached| from != page && !reached.contains(&page));
+    }
+
+    fn reachable(&self, from: Page) -> HashSet<Page> {
+        let mut reached = HashSet::new();
+        let mut stack = vec![from];
+
+        while let Some(page) = stack.pop() {
+            for &after in self.rules.get(&page).into_iter().flatten() {
+                if reached.insert(after) {
+                    stack.push(after);
+                }
+            }
+        }
+
+        reached
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::{HashMap, HashSet};
+
+use day_5::{minimal_moves, topological_order, violations, Manual, Move, Violation};
+
+fn sample() -> Manual {
+    day_5::parse(include_str!("../examples/sample.txt")).unwrap()
+}
+
+#[test]
+fn lists_broken_rules_with_positions() {
+    let Manual { rules, updates } = sample();
+    let pages = rules.pages();
+
+    assert!(rules.violations(&updates[0]).is_empty());
+    assert_eq!(
+        rules.violations(&updates[3]),
+        [Violation {
+            before: pages.get("97").unwrap(),
+            after: pages.get("75").unwrap(),
+            positions: (1, 0),
+        }]
+    );
+}
+
+#[test]
+fn moves_as_few_pages_as_possible() {
+    let Manual { rules, updates } = sample();
+    let pages = rules.pages();
+
+    assert_eq!(
+        rules.minimal_moves(&updates[5]),
+        Ok(vec![
+            Move {
+                page: pages.get("13").unwrap(),
+                from: 1,
+                to: 4,
+            },
+            Move {
+                page: pages.get("47").unwrap(),
+                from: 4,
+                to: 2,
+            },
+        ])
+    );
+}
+
+#[test]
+fn moves_match_brute_force() {
+    let mut seed = 7u64;
+    let mut random = |n: u64| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) % n
+    };
+
+    for _ in 0..300 {
+        let n = 2 + random(5) as u8;
+
+        // rules only ever point from a lower page to a higher one, so they never form a cycle
+        let mut rules: HashMap<u8, HashSet<u8>> = HashMap::new();
+
+        for a in 0..n {
+            for b in a + 1..n {
+                if random(3) == 0 {
+                    rules.entry(a).or_default().insert(b);
+                }
+            }
+        }
+
+        let mut update: Vec<u8> = (0..n).collect();
+
+        for i in (1..update.len()).rev() {
+            update.swap(i, random(i as u64 + 1) as usize);
+        }
+
+        let moves = minimal_moves(&rules, &update).unwrap();
+
+        let mut moved = update.clone();
+        let pages: Vec<_> = moves.iter().map(|m| m.page).collect();
+        moved.retain(|p| !pages.contains(p));
+
+        let mut targets: Vec<_> = moves.iter().map(|m| (m.to, m.page)).collect();
+        targets.sort();
+
+        for (to, page) in targets {
+            moved.insert(to, page);
+        }
+
+        assert!(violations(&rules, &moved).is_empty(), "{:?}", update);
+        assert_eq!(moves.len(), fewest_moves(&rules, &update), "{:?}", update);
+        assert!(topological_order(&rules, &moved).is_ok());
+    }
+}
+
+/// Every page moved at most once leaves the longest common subsequence with some valid order
+/// in place.
+fn fewest_moves(rules: &HashMap<u8, HashSet<u8>>, update: &[u8]) -> usize {
+    let mut fewest = update.len();
+    permutations(&mut update.to_vec(), 0, &mut |order| {
+        if violations(rules, order).is_empty() {
+            fewest = fewest.min(update.len() - common_subsequence(update, order));
+        }
+    });
+
+    fewest
+}
+
+fn permutations(pages: &mut [u8], k: usize, f: &mut impl FnMut(&[u8])) {
+    if k == pages.len() {
+        f(pages);
+        return;
+    }
+
+    for i in k..pages.len() {
+        pages.swap(k, i);
+        permutations(pages, k + 1, f);
+        pages.swap(k, i);
+    }
+}
+
+fn common_subsequence(a: &[u8], b: &[u8]) -> usize {
+    let mut lengths = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for i in 0..a.len() {
+        for j in 0..b.len() {
+            lengths[i + 1][j + 1] = if a[i] == b[j] {
+                lengths[i][j] + 1
+            } else {
+                lengths[i][j + 1].max(lengths[i + 1][j])
+            };
+        }
+    }
+
+    lengths[a.len()][b.len()]
+}
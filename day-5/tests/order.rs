@@ -0,0 +1,89 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::Answer;
+use day_5::{topological_order, Cycle, Manual, Order, Page};
+
+fn rules(pairs: &[(u8, u8)]) -> HashMap<u8, HashSet<u8>> {
+    let mut rules: HashMap<u8, HashSet<u8>> = HashMap::new();
+
+    for &(a, b) in pairs {
+        rules.entry(a).or_default().insert(b);
+    }
+
+    rules
+}
+
+#[test]
+fn follows_rules_through_pages_without_a_direct_rule() {
+    // 3 and 1 have no rule between them, which is not the same as being equal
+    let rules = rules(&[(1, 2), (2, 3)]);
+
+    assert_eq!(
+        topological_order(&rules, &[3, 1, 2]),
+        Ok(Order {
+            pages: vec![1, 2, 3],
+            unique: true,
+        })
+    );
+}
+
+#[test]
+fn flags_orders_that_are_not_unique() {
+    let rules = rules(&[(1, 3), (2, 3)]);
+
+    assert_eq!(
+        topological_order(&rules, &[2, 3, 1]),
+        Ok(Order {
+            pages: vec![2, 1, 3],
+            unique: false,
+        })
+    );
+}
+
+#[test]
+fn ignores_rules_about_other_pages() {
+    let rules = rules(&[(1, 2), (2, 3), (3, 1)]);
+
+    assert!(topological_order(&rules, &[1, 3]).is_ok());
+}
+
+#[test]
+fn lists_cycles() {
+    let rules = rules(&[(1, 2), (2, 3), (3, 1), (4, 1)]);
+    let cycle = topological_order(&rules, &[4, 3, 2, 1]).unwrap_err();
+
+    assert_eq!(cycle.0.len(), 3);
+    assert_eq!(cycle.to_string().matches(" -> ").count(), 3);
+
+    let rotated: Vec<_> = cycle
+        .0
+        .iter()
+        .cycle()
+        .skip_while(|&&p| p != 1)
+        .take(3)
+        .copied()
+        .collect();
+    assert_eq!(Cycle(rotated), Cycle(vec![1, 2, 3]));
+}
+
+#[test]
+fn rejects_updates_with_contradictory_rules() {
+    let e = day_5::parse("1|2\n2|1\n\n1,3\n2,1").unwrap_err();
+
+    assert_eq!(e.line(), 5);
+    assert!(e.expected().contains("cycle"), "{}", e);
+}
+
+#[test]
+fn orders_pages_by_label() {
+    let manual =
+        day_5::parse("core|net\nnet|app\n1000|2000\n\napp,core,net\n2000,1000,3000").unwrap();
+    let Manual { rules, updates } = &manual;
+    let order = |update: &[Page]| rules.order(update).unwrap().pages;
+
+    assert_eq!(rules.labels(&order(&updates[0])), ["core", "net", "app"]);
+    assert_eq!(rules.labels(&order(&updates[1])), ["1000", "2000", "3000"]);
+
+    assert_eq!(day_5::part1(&manual), 0.into());
+    assert_eq!(day_5::part2(&manual), Answer::Text("net,2000".to_owned()));
+}
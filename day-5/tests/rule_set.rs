@@ -0,0 +1,116 @@
+use day_5::RuleSet;
+
+#[test]
+fn checks_updates_streamed_after_the_rules() {
+    let mut rules = RuleSet::parse(
+        include_str!("../examples/sample.txt")
+            .split("\n\n")
+            .next()
+            .unwrap(),
+    )
+    .unwrap();
+
+    let update = rules.parse_update("75,97,47,61,53").unwrap();
+    assert!(!rules.is_ordered(&update));
+    assert_eq!(
+        rules.labels(&rules.order(&update).unwrap().pages),
+        ["97", "75", "47", "61", "53"]
+    );
+
+    // pages without rules are left where they are
+    let update = rules.parse_update("75,100,47").unwrap();
+    assert!(rules.is_ordered(&update));
+
+    assert!(rules.parse_update("75,,47").is_err());
+}
+
+#[test]
+fn reads_crlf_rules_and_updates() {
+    let mut rules = RuleSet::parse("\u{feff}47|53\r\n97|47\r\n").unwrap();
+
+    assert_eq!(rules.rules().values().map(|s| s.len()).sum::<usize>(), 2);
+
+    let update = rules.parse_update("\u{feff}53,47,97\r").unwrap();
+    assert_eq!(rules.labels(&update), ["53", "47", "97"]);
+    assert_eq!(
+        rules.labels(&rules.order(&update).unwrap().pages),
+        ["97", "47", "53"]
+    );
+    assert_eq!(rules.pages().len(), 3);
+}
+
+#[test]
+fn adds_and_removes_rules() {
+    let mut rules = RuleSet::parse("a|b\nb|c").unwrap();
+    let update = rules.parse_update("c,a").unwrap();
+    let (a, c) = (update[1], update[0]);
+
+    assert!(rules.implies(a, c));
+    assert!(rules.is_ordered(&update));
+
+    assert!(rules.add("a", "c"));
+    assert!(!rules.add("a", "c"));
+    assert!(!rules.is_ordered(&update));
+
+    assert!(rules.remove("a", "c"));
+    assert!(rules.remove("b", "c"));
+    assert!(!rules.remove("b", "c"));
+    assert!(!rules.implies(a, c));
+
+    assert!(rules.add("c", "a"));
+    assert!(rules.implies(c, a));
+    assert!(rules.is_ordered(&update));
+}
+
+#[test]
+fn implies_follows_rules_added_and_removed_after_asking() {
+    let mut rules = RuleSet::parse("a|b").unwrap();
+    let [a, b, c, d] = ["a", "b", "c", "d"].map(|label| rules.intern(label));
+
+    assert!(rules.implies(a, b));
+    assert!(!rules.implies(a, c));
+    assert!(!rules.implies(b, c));
+
+    // extending the chain past a page already reached
+    rules.add("b", "c");
+    assert!(rules.implies(a, c));
+    assert!(rules.implies(b, c));
+
+    rules.add("c", "d");
+    assert!(rules.implies(a, d));
+
+    // breaking it in the middle
+    rules.remove("b", "c");
+    assert!(rules.implies(a, b));
+    assert!(!rules.implies(a, c));
+    assert!(!rules.implies(a, d));
+    assert!(!rules.implies(b, d));
+    assert!(rules.implies(c, d));
+
+    // a rule that does not lead through the cached pages leaves them as they were
+    rules.add("d", "c");
+    assert!(rules.implies(c, d));
+    assert!(rules.implies(d, c));
+    assert!(!rules.implies(a, c));
+
+    rules.add("b", "d");
+    assert!(rules.implies(a, c));
+    assert!(rules.implies(a, d));
+
+    rules.remove("a", "b");
+    assert!(!rules.implies(a, b));
+    assert!(!rules.implies(a, d));
+    assert!(rules.implies(b, c));
+}
+
+#[test]
+fn reports_cycles() {
+    let mut rules = RuleSet::parse("a|b\nb|c").unwrap();
+    rules.add("c", "a");
+
+    let update = rules.parse_update("a,b,c").unwrap();
+    let cycle = rules.order(&update).unwrap_err();
+
+    assert_eq!(cycle.0.len(), 3);
+    assert!(rules.order(&update[..2]).is_ok());
+}
@@ -0,0 +1,245 @@
+use std::collections::HashSet;
+
+use aoc_core::{Answer, ParseError, Result, Solution};
+use aoc_grid::{Grid, Point};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// Obstacle positions.
+pub type Map = Grid<bool>;
+
+/// Where the guard stops from each cell in each direction: the cell before the next obstacle, or
+/// `None` if the guard walks off the map first. This lets the guard jump from turn to turn.
+struct Jumps<'m> {
+    map: &'m Map,
+    stops: Grid<[Option<Point>; 4]>,
+}
+
+/// A cell the guard first walks into from `from`, facing `direction`.
+struct Visit {
+    point: Point,
+    from: Point,
+    direction: Direction,
+}
+
+pub type Input = (Map, Point);
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    parse_input(input)
+}
+
+pub fn part1((map, start): &Input) -> Answer {
+    let visits = Jumps::new(map).walk(*start);
+    let distinct_point_count = visits.len() + 1;
+
+    distinct_point_count.into()
+}
+
+/// Tries an obstacle in each cell of the guard's path, other than the start. Each try starts
+/// from just before the guard would first walk into the obstacle, since the path up to there is
+/// the same.
+pub fn part2((map, start): &Input) -> Answer {
+    let jumps = Jumps::new(map);
+    let mut turns = HashSet::new();
+
+    let loop_possibility_count = jumps
+        .walk(*start)
+        .iter()
+        .filter(|v| jumps.loops(v.from, v.direction, Some(v.point), &mut turns))
+        .count();
+
+    loop_possibility_count.into()
+}
+
+fn parse_input(i: &str) -> Result<(Map, Point)> {
+    let chars = Grid::parse(i, |c| c).map_err(|e| ParseError::from_grid(i, e))?;
+
+    let start = chars
+        .iter()
+        .find(|&(_, &c)| c == '^')
+        .map(|(p, _)| p)
+        .ok_or_else(|| ParseError::at(i, i.len(), "guard start '^'"))?;
+
+    Ok((chars.map(|&c| c == '#'), start))
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    fn turn(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// How far `to` is ahead of `from`, if it is straight ahead.
+    fn distance(self, Point(x, y): Point, Point(to_x, to_y): Point) -> Option<usize> {
+        match self {
+            Direction::Up if x == to_x && to_y < y => Some(y - to_y),
+            Direction::Down if x == to_x && to_y > y => Some(to_y - y),
+            Direction::Left if y == to_y && to_x < x => Some(x - to_x),
+            Direction::Right if y == to_y && to_x > x => Some(to_x - x),
+            _ => None,
+        }
+    }
+}
+
+impl<'m> Jumps<'m> {
+    fn new(map: &'m Map) -> Self {
+        let mut stops = map.map(|_| [None; 4]);
+
+        for direction in Direction::ALL {
+            // each cell's stop follows from the next cell's, so work back from the far edge
+            let mut points: Vec<_> = map.points().collect();
+
+            if matches!(direction, Direction::Right | Direction::Down) {
+                points.reverse();
+            }
+
+            for point in points {
+                stops[point][direction as usize] = match map.translate(point, direction.delta()) {
+                    None => None,
+                    Some(next) if map[next] => Some(point),
+                    Some(next) => stops[next][direction as usize],
+                };
+            }
+        }
+
+        Jumps { map, stops }
+    }
+
+    /// Where the guard stops walking from `position`, with an extra obstacle if any.
+    fn jump(
+        &self,
+        position: Point,
+        direction: Direction,
+        obstacle: Option<Point>,
+    ) -> Option<Point> {
+        let stop = self.stops[position][direction as usize];
+
+        let Some(ahead) = obstacle.and_then(|o| direction.distance(position, o)) else {
+            return stop;
+        };
+
+        let stop_distance =
+            stop.map_or(usize::MAX, |s| direction.distance(position, s).unwrap_or(0));
+
+        if ahead <= stop_distance {
+            let (dx, dy) = direction.delta();
+            let Point(x, y) = position;
+
+            // the obstacle is inside the map, so the cell before it is too
+            Some(Point(
+                x.wrapping_add_signed(dx * (ahead as isize - 1)),
+                y.wrapping_add_signed(dy * (ahead as isize - 1)),
+            ))
+        } else {
+            stop
+        }
+    }
+
+    /// Follows the guard from the start cell by cell until it leaves the map or loops,
+    /// recording each cell other than the start when it is first walked into.
+    fn walk(&self, start: Point) -> Vec<Visit> {
+        let mut visited = self.map.map(|_| false);
+        let mut turns = HashSet::new();
+        let mut visits = vec![];
+
+        let mut position = start;
+        let mut direction = Direction::Up;
+        visited[start] = true;
+
+        loop {
+            let stop = self.jump(position, direction, None);
+
+            while Some(position) != stop {
+                let Some(next) = self.map.translate(position, direction.delta()) else {
+                    return visits;
+                };
+
+                if !visited[next] {
+                    visited[next] = true;
+                    visits.push(Visit {
+                        point: next,
+                        from: position,
+                        direction,
+                    });
+                }
+
+                position = next;
+            }
+
+            if !turns.insert((position, direction)) {
+                return visits;
+            }
+
+            direction = direction.turn();
+        }
+    }
+
+    /// Whether the guard walks in a loop from `position`, with an extra obstacle if any. Only
+    /// the turns are followed, and `turns` is reused between calls to save allocating.
+    fn loops(
+        &self,
+        mut position: Point,
+        mut direction: Direction,
+        obstacle: Option<Point>,
+        turns: &mut HashSet<(Point, Direction)>,
+    ) -> bool {
+        turns.clear();
+
+        while let Some(stop) = self.jump(position, direction, obstacle) {
+            if !turns.insert((stop, direction)) {
+                return true;
+            }
+
+            position = stop;
+            direction = direction.turn();
+        }
+
+        false
+    }
+}
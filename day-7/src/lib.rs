@@ -0,0 +1,116 @@
+use std::iter::zip;
+
+use aoc_core::{parse_lines, Answer, IResult, Result, Solution};
+use nom::{
+    character::complete::{char, space1, u64},
+    multi::separated_list1,
+    sequence::{separated_pair, tuple},
+};
+
+enum Op {
+    Add,
+    Mul,
+    Concat,
+}
+
+pub struct Equation {
+    value: u64,
+    operands: Vec<u64>,
+}
+
+pub type Input = Vec<Equation>;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    parse_lines(input, parse_line)
+}
+
+pub fn part1(equations: &Input) -> Answer {
+    let add_mul_count = equations
+        .iter()
+        .filter(|&equation| is_possible(&[Op::Add, Op::Mul], equation))
+        .fold(0, |acc, Equation { value, .. }| acc + value);
+
+    add_mul_count.into()
+}
+
+pub fn part2(equations: &Input) -> Answer {
+    let add_mul_concat_count = equations
+        .iter()
+        .filter(|&equation| is_possible(&[Op::Add, Op::Mul, Op::Concat], equation))
+        .fold(0, |acc, Equation { value, .. }| acc + value);
+
+    add_mul_concat_count.into()
+}
+
+fn parse_line(i: &str) -> IResult<'_, Equation> {
+    separated_pair(
+        u64,
+        tuple((char(':'), space1)),
+        separated_list1(space1, u64),
+    )(i)
+    .map(|(i, (value, operands))| (i, Equation { value, operands }))
+}
+
+fn evaluate(operands: &[u64], operators: &[&Op]) -> u64 {
+    let mut result = operands[0];
+
+    for (operand, &op) in zip(operands.iter().skip(1), operators) {
+        result = match op {
+            Op::Add => result + operand,
+            Op::Mul => result * operand,
+            Op::Concat => {
+                (result * 10_u64.pow(operand.checked_ilog10().unwrap_or(0) + 1)) + operand
+            }
+        }
+    }
+
+    result
+}
+
+fn is_possible(operators: &[Op], equation: &Equation) -> bool {
+    permute(operators, equation.operands.len() - 1)
+        .iter()
+        .any(|operators| evaluate(&equation.operands, operators) == equation.value)
+}
+
+fn permute(operators: &[Op], count: usize) -> Vec<Vec<&Op>> {
+    match count {
+        0 => vec![],
+        1 => operators.iter().map(|op| vec![op]).collect(),
+        _ => {
+            let mut result = vec![];
+
+            for op in operators.iter() {
+                let mut permutations = permute(operators, count - 1);
+
+                for permutation in permutations.iter_mut() {
+                    permutation.push(op);
+                }
+
+                result.append(&mut permutations);
+            }
+
+            result
+        }
+    }
+}
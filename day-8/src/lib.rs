@@ -0,0 +1,80 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{Answer, ParseError, Result, Solution};
+use aoc_grid::{Grid, Point};
+
+pub struct Map {
+    antennas: HashMap<char, HashSet<Point>>,
+    grid: Grid<char>,
+}
+
+pub type Input = Map;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    parse_input(input)
+}
+
+pub fn part1(map: &Input) -> Answer {
+    find_antinodes(map, false).len().into()
+}
+
+pub fn part2(map: &Input) -> Answer {
+    find_antinodes(map, true).len().into()
+}
+
+fn parse_input(i: &str) -> Result<Map> {
+    let grid = Grid::parse(i, |c| c).map_err(|e| ParseError::from_grid(i, e))?;
+
+    let mut antennas: HashMap<char, HashSet<Point>> = HashMap::new();
+
+    for (point, &char) in grid.iter() {
+        if char != '.' {
+            antennas.entry(char).or_default().insert(point);
+        }
+    }
+
+    Ok(Map { antennas, grid })
+}
+
+fn find_antinodes(map: &Map, all: bool) -> HashSet<Point> {
+    map.antennas
+        .values()
+        .flat_map(|antennas| {
+            antennas
+                .iter()
+                .flat_map(|a| antennas.iter().map(move |b| (a, b)))
+                .filter(|&(a, b)| a != b)
+                .flat_map(|(&Point(xa, ya), &b)| {
+                    let Point(xb, yb) = b;
+                    let step = (xb as isize - xa as isize, yb as isize - ya as isize);
+                    let ray = map.grid.ray(b, step);
+
+                    if all {
+                        ray.collect::<Vec<_>>()
+                    } else {
+                        ray.skip(1).take(1).collect()
+                    }
+                })
+        })
+        .collect()
+}
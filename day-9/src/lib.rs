@@ -0,0 +1,126 @@
+use aoc_core::{Answer, ParseError, Result, Solution};
+
+#[derive(Clone)]
+pub struct File {
+    id: usize,
+    offset: usize,
+    size: usize,
+}
+
+pub type Input = Vec<File>;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Answer {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    parse_input(input)
+}
+
+pub fn part1(files: &Input) -> Answer {
+    let mut disk = materialize(files);
+    rearrange_blocks(&mut disk);
+
+    checksum(&disk).into()
+}
+
+pub fn part2(files: &Input) -> Answer {
+    let mut files = files.clone();
+    rearrange_files(&mut files);
+    let disk = materialize(&files);
+
+    checksum(&disk).into()
+}
+
+fn parse_input(i: &str) -> Result<Vec<File>> {
+    let mut id = 0;
+    let mut offset = 0;
+    let mut files = Vec::new();
+
+    for (index, char) in i.trim_end().char_indices() {
+        let size = char
+            .to_digit(10)
+            .ok_or_else(|| ParseError::at(i, index, "digit"))? as usize;
+
+        if index % 2 == 0 {
+            files.push(File { id, offset, size });
+            id += 1;
+        }
+
+        offset += size;
+    }
+
+    Ok(files)
+}
+
+fn materialize(files: &[File]) -> Vec<Option<usize>> {
+    let mut disk = vec![None; files.last().map_or(0, |f| f.offset + f.size)];
+
+    for file in files {
+        disk[file.offset..file.offset + file.size].fill(Some(file.id));
+    }
+
+    disk
+}
+
+fn rearrange_blocks(disk: &mut [Option<usize>]) {
+    let mut from = disk.len().saturating_sub(1);
+    let mut to = 0;
+
+    loop {
+        while from > 0 && disk[from].is_none() {
+            from -= 1;
+        }
+
+        while to < from && disk[to].is_some() {
+            to += 1;
+        }
+
+        if from <= to {
+            break;
+        }
+
+        disk.swap(from, to);
+    }
+}
+
+fn rearrange_files(files: &mut [File]) {
+    let mut from = files.len().saturating_sub(1);
+
+    'outer: while from > 0 {
+        for to in 0..from {
+            let offset = files[to].offset + files[to].size;
+
+            if files[to + 1].offset - offset >= files[from].size {
+                files[from].offset = offset;
+                files[to + 1..=from].rotate_right(1);
+                continue 'outer;
+            }
+        }
+
+        from -= 1;
+    }
+}
+
+fn checksum(disk: &[Option<usize>]) -> usize {
+    disk.iter()
+        .enumerate()
+        .filter_map(|(i, id)| id.map(|id| i * id))
+        .sum()
+}